@@ -0,0 +1,183 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc_common::{Result, workspace};
+use clap::Args;
+use serde_json::{Value, json};
+
+use crate::Select;
+
+#[derive(Args)]
+pub struct Options {
+    /// Runs to throw away before we start measuring.
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+    /// Runs to measure.
+    #[arg(short = 'n', long, default_value_t = 10)]
+    runs: usize,
+    /// Where to write the JSON results. Defaults to bench_output.txt in the workspace.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Results from a previous run to compare against.
+    #[arg(short, long)]
+    baseline: Option<PathBuf>,
+    /// How much slower (in percent) a part can get before we call it a regression.
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    p95: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let at = |p: f64| samples[((samples.len() as f64 * p).ceil() as usize).max(1) - 1];
+        Self {
+            min: samples[0],
+            median: at(0.5),
+            p95: at(0.95),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "min": self.min.as_nanos() as u64,
+            "median": self.median.as_nanos() as u64,
+            "p95": self.p95.as_nanos() as u64,
+        })
+    }
+}
+
+// The key we match results against the baseline with.
+fn key(result: &Value) -> Option<(u64, u64, &str)> {
+    Some((
+        result["year"].as_u64()?,
+        result["day"].as_u64()?,
+        result["name"].as_str()?,
+    ))
+}
+
+// The median time for the whole part (parse and solve) in nanoseconds.
+fn median(result: &Value) -> u64 {
+    result["parse"]["median"].as_u64().unwrap_or(0)
+        + result["solve"]["median"].as_u64().unwrap_or(0)
+}
+
+pub fn bench(select: &Select, options: &Options) -> Result<()> {
+    if options.runs == 0 {
+        return Err("--runs must be at least 1".into());
+    }
+
+    let baseline: Vec<Value> = match &options.baseline {
+        Some(path) => {
+            let baseline: Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            baseline["results"].as_array().cloned().unwrap_or_default()
+        }
+        None => vec![],
+    };
+
+    let mut results = vec![];
+    let mut regressions = 0;
+    for (year, day) in select.days()? {
+        println!("{} day {:02}", year.year, day.day);
+        let Some(input) = select.input(year, day)? else {
+            continue;
+        };
+
+        for p in day.parts().filter(|p| select.wants(p.part())) {
+            let mut parse = vec![];
+            let mut solve = vec![];
+            let mut failed = None;
+            for i in 0..options.warmup + options.runs {
+                match p.run(&input) {
+                    Ok(timed) if i >= options.warmup => {
+                        parse.push(timed.parse);
+                        solve.push(timed.solve);
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        failed = Some(err);
+                        break;
+                    }
+                }
+            }
+            if let Some(err) = failed {
+                println!("{} error: {err}", p.name());
+                continue;
+            }
+
+            let (parse, solve) = (Stats::new(parse), Stats::new(solve));
+            let result = json!({
+                "year": year.year,
+                "day": day.day,
+                "part": p.part(),
+                "name": p.name(),
+                "parse": parse.to_json(),
+                "solve": solve.to_json(),
+            });
+
+            let mut line = format!(
+                "{:12} parse {:>10} solve min {:>10} median {:>10} p95 {:>10}",
+                p.name(),
+                format!("{:.2?}", parse.median),
+                format!("{:.2?}", solve.min),
+                format!("{:.2?}", solve.median),
+                format!("{:.2?}", solve.p95),
+            );
+            if let Some(base) = baseline.iter().find(|b| key(b) == key(&result)) {
+                let (before, after) = (median(base) as f64, median(&result) as f64);
+                let change = (after - before) / before.max(1.0) * 100.0;
+                line.push_str(&format!(" {change:+.1}%"));
+                if change > options.threshold {
+                    line.push_str(" REGRESSION");
+                    regressions += 1;
+                }
+            }
+            println!("{line}");
+            results.push(result);
+        }
+    }
+
+    let output = options
+        .output
+        .clone()
+        .unwrap_or_else(|| workspace().join("bench_output.txt"));
+    let report = json!({
+        "warmup": options.warmup,
+        "runs": options.runs,
+        "results": results,
+    });
+    std::fs::write(&output, serde_json::to_string_pretty(&report)?)?;
+    println!("\nresults written to {}", output.display());
+
+    if regressions > 0 {
+        return Err(format!(
+            "{regressions} part(s) regressed by more than {}%",
+            options.threshold
+        )
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        assert_eq!(
+            Stats::new(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(10),
+                p95: Duration::from_millis(19),
+            }
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{Result, input_path, workspace};
+
+// Advent of Code asks that automated tools identify themselves.
+const USER_AGENT: &str = "github.com/icub3d/advent-of-code by joshua.marshian@gmail.com";
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+// How long to wait between requests so we don't hammer the server.
+const INTERVAL: Duration = Duration::from_secs(3);
+
+/// A client for adventofcode.com. Responses that never change (like inputs) are cached on disk and
+/// requests are spaced out by at least `interval`.
+pub struct Client {
+    agent: ureq::Agent,
+    base: String,
+    session: Option<String>,
+    cache: PathBuf,
+    interval: Duration,
+}
+
+impl Client {
+    /// Build a client from the environment. `AOC_SESSION` is the session cookie from the site and
+    /// `AOC_URL` overrides where we send requests. The session is only needed once we actually
+    /// make a request, so cached pages can be read without it.
+    pub fn from_env() -> Self {
+        let base = std::env::var("AOC_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        let mut client = Self::new(&base, "", &workspace().join(".cache"), INTERVAL);
+        client.session = std::env::var("AOC_SESSION").ok();
+        client
+    }
+
+    pub fn new(base: &str, session: &str, cache: &Path, interval: Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base: base.trim_end_matches('/').to_string(),
+            session: Some(session.to_string()),
+            cache: cache.to_path_buf(),
+            interval,
+        }
+    }
+
+    /// Download the input for a day to its default location. Inputs we already have are left
+    /// alone. Returns where the input was written or None if it was already there.
+    pub fn download(&self, year: u16, day: u8) -> Result<Option<PathBuf>> {
+        self.download_to(year, day, &input_path(year, day))
+    }
+
+    pub fn download_to(&self, year: u16, day: u8, path: &Path) -> Result<Option<PathBuf>> {
+        if path.exists() {
+            return Ok(None);
+        }
+
+        let input = self.get_cached(year, day, &format!("/{year}/day/{day}/input"))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, input)?;
+        Ok(Some(path.to_path_buf()))
+    }
+
+    // GET something that won't change once the puzzle is out, using the disk cache if we can.
+    pub fn get_cached(&self, year: u16, day: u8, path: &str) -> Result<String> {
+        match std::fs::read_to_string(self.cache_path(path)) {
+            Ok(body) => Ok(body),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => self.refresh(year, day, path),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// GET something and replace whatever we had cached for it.
+    pub fn refresh(&self, year: u16, day: u8, path: &str) -> Result<String> {
+        let body = self.get(year, day, path)?;
+        std::fs::create_dir_all(&self.cache)?;
+        std::fs::write(self.cache_path(path), &body)?;
+        Ok(body)
+    }
+
+    pub fn get(&self, year: u16, day: u8, path: &str) -> Result<String> {
+        check_unlocked(year, day, SystemTime::now())?;
+        let cookie = self.cookie()?;
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base))
+            .set("Cookie", &cookie)
+            .call();
+        read(response)
+    }
+
+    pub fn post(&self, year: u16, day: u8, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        check_unlocked(year, day, SystemTime::now())?;
+        let cookie = self.cookie()?;
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(&format!("{}{path}", self.base))
+            .set("Cookie", &cookie)
+            .send_form(form);
+        read(response)
+    }
+
+    fn cookie(&self) -> Result<String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or("AOC_SESSION environment variable not set")?;
+        Ok(format!("session={session}"))
+    }
+
+    fn cache_path(&self, path: &str) -> PathBuf {
+        self.cache.join(path.trim_matches('/').replace('/', "-"))
+    }
+
+    // Wait until enough time has passed since the last request. We keep the time of the last
+    // request on disk so separate runs are spaced out as well.
+    fn throttle(&self) -> Result<()> {
+        let last = self.cache.join("last-request");
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        if let Ok(millis) = std::fs::read_to_string(&last)
+            && let Ok(millis) = millis.trim().parse::<u64>()
+        {
+            let next = Duration::from_millis(millis) + self.interval;
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        std::fs::create_dir_all(&self.cache)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        std::fs::write(&last, now.as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+fn read(response: std::result::Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(code, _)) => Err(match code {
+            401 => "received 401: your AOC_SESSION cookie might be invalid or expired".to_string(),
+            404 => "received 404: the puzzle for this day might not be unlocked yet".to_string(),
+            500 => "received 500: Advent of Code might be having server issues".to_string(),
+            code => format!("received {code}"),
+        }
+        .into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// When a puzzle unlocks: midnight EST (05:00 UTC) on the day in December.
+pub fn unlocks_at(year: u16, day: u8) -> SystemTime {
+    // Days since the epoch for a civil date (from Howard Hinnant's date algorithms).
+    let (y, m, d) = (year as i64, 12, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    UNIX_EPOCH + Duration::from_secs(days as u64 * 86400 + 5 * 3600)
+}
+
+// Refuse to ask for puzzles that don't exist or haven't unlocked yet.
+pub fn check_unlocked(year: u16, day: u8, now: SystemTime) -> Result<()> {
+    // Starting in 2025 there are only 12 days.
+    let days = if year >= 2025 { 12 } else { 25 };
+    if year < 2015 || !(1..=days).contains(&day) {
+        return Err(format!("there is no puzzle for {year} day {day}").into());
+    }
+
+    let unlocks = unlocks_at(year, day);
+    if let Ok(wait) = unlocks.duration_since(now)
+        && !wait.is_zero()
+    {
+        let secs = wait.as_secs();
+        return Err(format!(
+            "{year} day {day} unlocks in {}h{:02}m{:02}s",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    use super::*;
+
+    /// Serve the given responses, one per connection, and send back the requests we saw.
+    pub(crate) fn serve(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                tx.send(request).unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (base, rx)
+    }
+
+    pub(crate) fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_unlocks_at() {
+        // 2016-12-01T05:00:00Z
+        assert_eq!(
+            unlocks_at(2016, 1),
+            UNIX_EPOCH + Duration::from_secs(1480568400)
+        );
+
+        let now = unlocks_at(2025, 3) - Duration::from_secs(3661);
+        assert_eq!(
+            check_unlocked(2025, 3, now).unwrap_err().to_string(),
+            "2025 day 3 unlocks in 1h01m01s"
+        );
+        assert!(check_unlocked(2025, 3, unlocks_at(2025, 3)).is_ok());
+        assert!(check_unlocked(2025, 13, now).is_err());
+    }
+
+    #[test]
+    fn test_download() {
+        let dir = temp_dir("download");
+        let (base, requests) = serve(vec![(200, "1122\n".to_string())]);
+        let client = Client::new(&base, "abc", &dir.join("cache"), Duration::ZERO);
+
+        let path = dir.join("inputs").join("day01.txt");
+        assert_eq!(
+            client.download_to(2017, 1, &path).unwrap(),
+            Some(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1122\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2017/day/1/input "));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+
+        // Existing inputs are skipped and deleted ones come back from the cache.
+        assert_eq!(client.download_to(2017, 1, &path).unwrap(), None);
+        std::fs::remove_file(&path).unwrap();
+        assert!(client.download_to(2017, 1, &path).unwrap().is_some());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1122\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        let dir = temp_dir("errors");
+        let (base, _requests) = serve(vec![(401, String::new()), (500, String::new())]);
+        let client = Client::new(&base, "abc", &dir, Duration::ZERO);
+
+        let path = dir.join("day01.txt");
+        let err = client.download_to(2017, 1, &path).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("AOC_SESSION cookie might be invalid")
+        );
+        let err = client.download_to(2017, 1, &path).unwrap_err();
+        assert!(err.to_string().contains("server issues"));
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
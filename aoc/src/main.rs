@@ -0,0 +1,376 @@
+mod bench;
+mod client;
+mod scaffold;
+mod show;
+mod submit;
+
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_common::{
+    Answer, Answers, Day, Result, Status, Timed, Year, answers_path, input_hash, input_path,
+    load_input, workspace,
+};
+use clap::{Args, Parser, Subcommand};
+
+const YEARS: &[Year] = &[aoc_2015::YEAR, aoc_2016::YEAR, aoc_2025::YEAR];
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions. Leave off the day to run a whole year or the year to run everything.
+    Run {
+        #[command(flatten)]
+        select: Select,
+        /// Record answers we don't know yet without asking.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Make sure every variant of a part gives the same answer.
+    Check {
+        #[command(flatten)]
+        select: Select,
+    },
+    /// Download inputs from adventofcode.com. Leave off the day to get every day that's unlocked.
+    Get { year: u16, day: Option<u8> },
+    /// Solve a part and submit the answer to adventofcode.com.
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Read the input from this file instead of the inputs directory ("-" for stdin).
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Show the puzzle description. Pages are cached so they can be read offline.
+    Show {
+        year: u16,
+        day: u8,
+        /// Fetch the page again (e.g. to see part two once part one is solved).
+        #[arg(long)]
+        refresh: bool,
+        /// Wrap the text at this many columns. Defaults to $COLUMNS or 80.
+        #[arg(short, long)]
+        width: Option<usize>,
+    },
+    /// Start a new day from the year's template (and the year's crate if it's new).
+    New { year: u16, day: u8 },
+    /// Time solutions over many runs.
+    Bench {
+        #[command(flatten)]
+        select: Select,
+        #[command(flatten)]
+        options: bench::Options,
+    },
+}
+
+/// Which solutions to run and what to run them on.
+#[derive(Args)]
+struct Select {
+    year: Option<u16>,
+    day: Option<u8>,
+    #[arg(short, long)]
+    part: Option<u8>,
+    /// Read the input from this file instead of the inputs directory ("-" for stdin).
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+}
+
+impl Select {
+    fn days(&self) -> Result<Vec<(&'static Year, &'static Day)>> {
+        if self.input.is_some() && self.day.is_none() {
+            return Err("--input requires a year and day".into());
+        }
+
+        let years: Vec<&Year> = match self.year {
+            Some(year) => vec![
+                YEARS
+                    .iter()
+                    .find(|y| y.year == year)
+                    .ok_or(format!("no solutions for {year}"))?,
+            ],
+            None => YEARS.iter().collect(),
+        };
+
+        let mut days = vec![];
+        for year in years {
+            match self.day {
+                Some(day) => days.push((
+                    year,
+                    year.day(day)
+                        .ok_or(format!("no solution for {} day {day}", year.year))?,
+                )),
+                None => days.extend(year.days.iter().map(|day| (year, day))),
+            }
+        }
+        Ok(days)
+    }
+
+    // Missing inputs only stop us when a specific day was asked for.
+    fn input(&self, year: &Year, day: &Day) -> Result<Option<String>> {
+        match load_input(year.year, day.day, self.input.as_deref()) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if self.day.is_none() => {
+                println!("{err}");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn wants(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+// A single row in the summary table.
+struct Row {
+    year: u16,
+    day: u8,
+    name: &'static str,
+    elapsed: Duration,
+    answer: String,
+    status: Status,
+}
+
+fn run(select: &Select, record: bool) -> Result<()> {
+    // We can't ask about unknown answers if the input came in on stdin.
+    let interactive = io::stdin().is_terminal() && select.input.as_deref() != Some(Path::new("-"));
+    let mut answers = Answers::load(&answers_path())?;
+
+    let mut rows = vec![];
+    for (year, day) in select.days()? {
+        println!("{} day {:02}", year.year, day.day);
+        let Some(input) = select.input(year, day)? else {
+            continue;
+        };
+
+        let hash = input_hash(&input);
+        for p in day.parts().filter(|p| select.wants(p.part())) {
+            let now = Instant::now();
+            let solution = p.run(&input);
+            let elapsed = now.elapsed();
+            let (answer, elapsed) = match solution {
+                Ok(Timed {
+                    answer: Answer::Ocr { text, screen },
+                    ..
+                }) if text.contains('?') => {
+                    // Show the screen so we can read the letters ourselves.
+                    print!("{screen}");
+                    (text, elapsed)
+                }
+                Ok(timed) => (timed.answer.to_string(), timed.total()),
+                Err(err) => {
+                    println!("{} {:?} error: {err}", p.name(), elapsed);
+                    rows.push(Row {
+                        year: year.year,
+                        day: day.day,
+                        name: p.name(),
+                        elapsed,
+                        answer: format!("error: {err}"),
+                        status: Status::Unknown,
+                    });
+                    continue;
+                }
+            };
+
+            let mut status = answers.check(year.year, day.day, p.part(), &hash, &answer);
+            println!("{} {:?} {} {}", p.name(), elapsed, answer, status);
+            if status == Status::Unknown
+                && (record || (interactive && confirm(year.year, day.day, p.part(), &answer)?))
+            {
+                answers.record(year.year, day.day, p.part(), &hash, &answer);
+                answers.save()?;
+                status = Status::Pass;
+            }
+
+            rows.push(Row {
+                year: year.year,
+                day: day.day,
+                name: p.name(),
+                elapsed,
+                answer,
+                status,
+            });
+        }
+    }
+
+    summary(&rows);
+    Ok(())
+}
+
+fn check(select: &Select) -> Result<()> {
+    let mut failures = 0;
+    for (year, day) in select.days()? {
+        let Some(input) = select.input(year, day)? else {
+            continue;
+        };
+
+        match day.check_variants(&input) {
+            Ok(()) => println!("{} day {:02} ok", year.year, day.day),
+            Err(err) => {
+                println!("{} day {:02} {err}", year.year, day.day);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{failures} day(s) have variants that disagree").into());
+    }
+    Ok(())
+}
+
+fn get(year: u16, day: Option<u8>) -> Result<()> {
+    let client = client::Client::from_env();
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25)
+            .take_while(|&day| client::check_unlocked(year, day, SystemTime::now()).is_ok())
+            .collect(),
+    };
+
+    for day in days {
+        match client.download(year, day)? {
+            Some(path) => println!("saved {year} day {day} to '{}'", path.display()),
+            None => println!(
+                "input for {year} day {day} already exists at '{}', skipping",
+                input_path(year, day).display()
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: u8, input: Option<&Path>) -> Result<()> {
+    let solution = YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .and_then(|y| y.day(day))
+        .and_then(|d| d.parts().find(|p| p.part() == part))
+        .ok_or(format!("no solution for {year} day {day} part {part}"))?;
+    let input = load_input(year, day, input)?;
+    let answer = solution.run(&input)?.answer.to_string();
+    println!("submitting {answer} for {year} day {day} part {part}");
+
+    let client = client::Client::from_env();
+    let mut history = submit::History::load(&submit::history_path())?;
+    let mut answers = Answers::load(&answers_path())?;
+    let verdict = submit::submit(
+        &client,
+        &mut history,
+        &mut answers,
+        (year, day, part),
+        &input,
+        &answer,
+    )?;
+    println!("{verdict}");
+    Ok(())
+}
+
+fn show(year: u16, day: u8, refresh: bool, width: Option<usize>) -> Result<()> {
+    let client = client::Client::from_env();
+    let path = format!("/{year}/day/{day}");
+    let page = match refresh {
+        true => client.refresh(year, day, &path)?,
+        false => client.get_cached(year, day, &path)?,
+    };
+
+    let width = width
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+        .unwrap_or(80);
+    print!(
+        "{}",
+        show::render(&page, width, io::stdout().is_terminal())?
+    );
+    Ok(())
+}
+
+fn new(year: u16, day: u8) -> Result<()> {
+    for path in scaffold::scaffold(workspace(), year, day)? {
+        println!("wrote '{}'", path.display());
+    }
+    Ok(())
+}
+
+fn confirm(year: u16, day: u8, part: u8, answer: &str) -> Result<bool> {
+    print!("record {answer} as the answer for {year} day {day} part {part}? [y/N] ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(matches!(line.trim(), "y" | "Y" | "yes"))
+}
+
+fn summary(rows: &[Row]) {
+    if rows.len() < 2 {
+        return;
+    }
+
+    // Multi-line answers (error messages) would break the table, so only show the first line.
+    let answer = |row: &Row| row.answer.lines().next().unwrap_or("").to_string();
+    let width = rows
+        .iter()
+        .map(|r| r.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("part".len());
+
+    println!();
+    println!(
+        "year day {:width$} {:>12} {:7} answer",
+        "part", "time", "status"
+    );
+    for row in rows {
+        let status = match row.status {
+            Status::Fail(_) => "FAIL".to_string(),
+            ref status => status.to_string(),
+        };
+        println!(
+            "{} {:>3} {:width$} {:>12} {:7} {}",
+            row.year,
+            row.day,
+            row.name,
+            format!("{:.2?}", row.elapsed),
+            status,
+            answer(row)
+        );
+    }
+    let total: Duration = rows.iter().map(|r| r.elapsed).sum();
+    println!(
+        "{:>w$} {:>12}",
+        "total",
+        format!("{total:.2?}"),
+        w = 9 + width
+    );
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run { select, record } => run(&select, record),
+        Command::Check { select } => check(&select),
+        Command::Get { year, day } => get(year, day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            input,
+        } => submit(year, day, part, input.as_deref()),
+        Command::Show {
+            year,
+            day,
+            refresh,
+            width,
+        } => show(year, day, refresh, width),
+        Command::New { year, day } => new(year, day),
+        Command::Bench { select, options } => bench::bench(&select, &options),
+    }
+}
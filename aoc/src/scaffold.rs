@@ -0,0 +1,284 @@
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Result;
+
+/// Set up a new day in the workspace at `root`: the year's crate (if it's new), the day's source
+/// file from the year's template, an empty sample input and the registration with the runner.
+/// Existing files are never overwritten. Returns the files that were created or changed.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let krate = root.join(format!("aoc_{year}"));
+    let name = format!("day{day:02}");
+    let source = krate.join("src").join(format!("{name}.rs"));
+    if source.exists() {
+        return Err(format!("'{}' already exists", source.display()).into());
+    }
+
+    let mut changed = vec![];
+    if !krate.exists() {
+        changed.extend(new_crate(root, year)?);
+    }
+
+    let template = std::fs::read_to_string(krate.join("template.rs"))?
+        .replace("[NAME]", &name)
+        .replace("[DAY]", &day.to_string())
+        .replace("[STRUCT]", &format!("Day{day:02}"));
+    create(&source, &template)?;
+    changed.push(source);
+
+    let sample = krate.join("inputs").join(format!("{name}-sample.txt"));
+    if !sample.exists() {
+        create(&sample, "")?;
+        changed.push(sample);
+    }
+
+    let lib = krate.join("src").join("lib.rs");
+    std::fs::write(&lib, register(&std::fs::read_to_string(&lib)?, year, &name))?;
+    changed.push(lib);
+    Ok(changed)
+}
+
+// Write a file that must not already exist.
+fn create(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::AlreadyExists => format!("'{}' already exists", path.display()),
+            _ => format!("unable to create '{}': {err}", path.display()),
+        })?;
+    file.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+// Create the crate for a year and hook it up to the workspace and the runner.
+fn new_crate(root: &Path, year: u16) -> Result<Vec<PathBuf>> {
+    let name = format!("aoc_{year}");
+    let krate = root.join(&name);
+
+    // Start from the template of the most recent year that has one.
+    let mut years = years(root)?;
+    years.sort();
+    let template = years
+        .iter()
+        .rev()
+        .map(|y| root.join(format!("aoc_{y}")).join("template.rs"))
+        .find(|t| t.exists())
+        .ok_or("no template to start the new year from")?;
+    let template = std::fs::read_to_string(template)?;
+
+    let manifest = format!(
+        "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\naoc_common = {{ path = \"../aoc_common\" }}\nrustc-hash = {{ workspace = true }}\nitertools = {{ workspace = true }}\n"
+    );
+    let files = [
+        (krate.join("Cargo.toml"), manifest),
+        (krate.join("template.rs"), template),
+        (
+            krate.join("src").join("lib.rs"),
+            format!("use aoc_common::Year;\n\n{}", year_const(year, &[])),
+        ),
+    ];
+    for (path, contents) in &files {
+        create(path, contents)?;
+    }
+    let mut changed: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+
+    // The workspace members.
+    let path = root.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&path)?;
+    let (start, end) = manifest
+        .find("members = [")
+        .and_then(|start| Some((start, start + manifest[start..].find(']')?)))
+        .ok_or("no workspace members in Cargo.toml")?;
+    let mut members: Vec<String> = manifest[start + "members = [".len()..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"').to_string())
+        .filter(|m| !m.is_empty())
+        .collect();
+    members.push(name.clone());
+    members.sort();
+    members.dedup();
+    let members: String = members.iter().map(|m| format!("    \"{m}\",\n")).collect();
+    let manifest = format!(
+        "{}members = [\n{members}{}",
+        &manifest[..start],
+        &manifest[end..]
+    );
+    std::fs::write(&path, manifest)?;
+    changed.push(path);
+
+    // The runner's dependencies.
+    let path = root.join("aoc").join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&path)?;
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    let at = lines
+        .iter()
+        .rposition(|l| l.starts_with("aoc_") && l[4..].starts_with(|c: char| c.is_ascii_digit()))
+        .map(|i| i + 1)
+        .ok_or("no year dependencies in aoc/Cargo.toml")?;
+    lines.insert(at, &dependency);
+    std::fs::write(&path, lines.join("\n") + "\n")?;
+    changed.push(path);
+
+    // The runner's list of years.
+    let path = root.join("aoc").join("src").join("main.rs");
+    let main = std::fs::read_to_string(&path)?;
+    let start = main
+        .find("const YEARS")
+        .ok_or("no YEARS in aoc/src/main.rs")?;
+    let end = start + main[start..].find(";\n").ok_or("no end to YEARS")? + 2;
+    years.push(year);
+    let years: Vec<String> = years.iter().map(|y| format!("aoc_{y}::YEAR")).collect();
+    let line = format!("const YEARS: &[Year] = &[{}];\n", years.join(", "));
+    let list = if line.len() <= 101 {
+        line
+    } else {
+        let years: String = years.iter().map(|y| format!("    {y},\n")).collect();
+        format!("const YEARS: &[Year] = &[\n{years}];\n")
+    };
+    std::fs::write(&path, format!("{}{list}{}", &main[..start], &main[end..]))?;
+    changed.push(path);
+
+    Ok(changed)
+}
+
+// The years that already have a crate.
+fn years(root: &Path) -> Result<Vec<u16>> {
+    let mut years = vec![];
+    for entry in std::fs::read_dir(root)? {
+        let name = entry?.file_name();
+        if let Some(year) = name.to_str().and_then(|n| n.strip_prefix("aoc_"))
+            && let Ok(year) = year.parse()
+        {
+            years.push(year);
+        }
+    }
+    Ok(years)
+}
+
+// Add a day to a year's lib.rs, keeping the modules and days sorted.
+fn register(lib: &str, year: u16, name: &str) -> String {
+    let mut days: Vec<&str> = lib
+        .lines()
+        .filter_map(|l| l.strip_prefix("pub mod ")?.strip_suffix(';'))
+        .filter(|m| m.starts_with("day"))
+        .collect();
+    days.push(name);
+    days.sort();
+    days.dedup();
+
+    // Anything other than the day modules and the YEAR const is left alone.
+    let start = lib.find("pub const YEAR").unwrap_or(lib.len());
+    let end = lib[start..]
+        .find(");\n")
+        .map(|i| start + i + 3)
+        .unwrap_or(lib.len());
+    let rest: Vec<&str> = lib[..start]
+        .lines()
+        .filter(|l| !(l.starts_with("pub mod day") && l.ends_with(';')))
+        .collect();
+    let header = rest.join("\n").trim_end().to_string();
+    let mods: String = days.iter().map(|d| format!("pub mod {d};\n")).collect();
+
+    format!(
+        "{header}\n\n{mods}\n{}{}",
+        year_const(year, &days),
+        &lib[end..]
+    )
+}
+
+// The YEAR const formatted the way rustfmt would.
+fn year_const(year: u16, days: &[&str]) -> String {
+    let entries: Vec<String> = days.iter().map(|d| format!("{d}::DAY")).collect();
+    let list = format!("&[{}]", entries.join(", "));
+    let line = format!("pub const YEAR: Year = Year::new({year}, {list});\n");
+    if line.len() <= 101 {
+        line
+    } else {
+        let entries: String = entries.iter().map(|e| format!("        {e},\n")).collect();
+        format!("pub const YEAR: Year = Year::new(\n    {year},\n    &[\n{entries}    ],\n);\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::temp_dir;
+
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let lib = "use aoc_common::Year;\n\npub mod day01;\npub mod day03;\n\npub const YEAR: Year = Year::new(2017, &[day01::DAY, day03::DAY]);\n";
+        assert_eq!(
+            register(lib, 2017, "day02"),
+            "use aoc_common::Year;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub const YEAR: Year = Year::new(2017, &[day01::DAY, day02::DAY, day03::DAY]);\n"
+        );
+
+        let lib = (4..=6).fold(lib.to_string(), |lib, day| {
+            register(&lib, 2017, &format!("day{day:02}"))
+        });
+        assert!(lib.ends_with("pub mod day06;\n\npub const YEAR: Year = Year::new(\n    2017,\n    &[\n        day01::DAY,\n        day03::DAY,\n        day04::DAY,\n        day05::DAY,\n        day06::DAY,\n    ],\n);\n"));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = temp_dir("scaffold");
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc_2015\",\n]\n",
+            ),
+            (
+                "aoc/Cargo.toml",
+                "[dependencies]\naoc_2015 = { path = \"../aoc_2015\" }\nclap = { workspace = true }\n",
+            ),
+            (
+                "aoc/src/main.rs",
+                "const YEARS: &[Year] = &[aoc_2015::YEAR];\n\nfn main() {}\n",
+            ),
+            ("aoc_2015/template.rs", "// [NAME] [DAY] [STRUCT]\n"),
+        ];
+        for (path, contents) in files {
+            create(&root.join(path), contents).unwrap();
+        }
+
+        scaffold(&root, 2017, 3).unwrap();
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(read("aoc_2017/src/day03.rs"), "// day03 3 Day03\n");
+        assert_eq!(read("aoc_2017/inputs/day03-sample.txt"), "");
+        assert_eq!(
+            read("aoc_2017/src/lib.rs"),
+            "use aoc_common::Year;\n\npub mod day03;\n\npub const YEAR: Year = Year::new(2017, &[day03::DAY]);\n"
+        );
+        assert_eq!(
+            read("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc_2015\",\n    \"aoc_2017\",\n]\n"
+        );
+        assert_eq!(
+            read("aoc/Cargo.toml"),
+            "[dependencies]\naoc_2015 = { path = \"../aoc_2015\" }\naoc_2017 = { path = \"../aoc_2017\" }\nclap = { workspace = true }\n"
+        );
+        assert_eq!(
+            read("aoc/src/main.rs"),
+            "const YEARS: &[Year] = &[aoc_2015::YEAR, aoc_2017::YEAR];\n\nfn main() {}\n"
+        );
+
+        // Nothing gets overwritten.
+        std::fs::write(root.join("aoc_2017/src/day03.rs"), "solved").unwrap();
+        assert!(scaffold(&root, 2017, 3).is_err());
+        assert_eq!(read("aoc_2017/src/day03.rs"), "solved");
+
+        scaffold(&root, 2017, 1).unwrap();
+        assert!(read("aoc_2017/src/lib.rs").contains("pub mod day01;\npub mod day03;\n"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
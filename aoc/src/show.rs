@@ -0,0 +1,125 @@
+use aoc_common::Result;
+use scraper::{ElementRef, Html, Node, Selector};
+
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Render the puzzle description(s) on a page as text wrapped to `width`. With `ansi`, emphasis is
+/// shown in bold; without it, emphasis is wrapped in `*`.
+pub fn render(page: &str, width: usize, ansi: bool) -> Result<String> {
+    let page = Html::parse_document(page);
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    let mut out = String::new();
+    for article in page.select(&articles) {
+        for block in article.child_elements() {
+            render_block(&mut out, block, width, ansi);
+        }
+    }
+
+    if out.is_empty() {
+        return Err("no puzzle description found on the page".into());
+    }
+    Ok(out.trim_end().to_string() + "\n")
+}
+
+fn render_block(out: &mut String, block: ElementRef, width: usize, ansi: bool) {
+    match block.value().name() {
+        "pre" => {
+            // Code blocks are kept as is, just indented.
+            let mut code = String::new();
+            inline(&mut code, block, ansi);
+            for line in code.trim_end_matches('\n').lines() {
+                out.push_str(format!("    {line}").trim_end());
+                out.push('\n');
+            }
+        }
+        "ul" | "ol" => {
+            for item in block.child_elements() {
+                let options = textwrap::Options::new(width)
+                    .initial_indent("  - ")
+                    .subsequent_indent("    ");
+                out.push_str(&textwrap::fill(&collapse(item, ansi), options));
+                out.push('\n');
+            }
+        }
+        _ => {
+            out.push_str(&textwrap::fill(&collapse(block, ansi), width));
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+}
+
+// The text of an element with the whitespace collapsed the way a browser would.
+fn collapse(element: ElementRef, ansi: bool) -> String {
+    let mut text = String::new();
+    inline(&mut text, element, ansi);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn inline(out: &mut String, element: ElementRef, ansi: bool) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(e) => {
+                let Some(child) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                let (open, close) = match (e.name(), ansi) {
+                    ("em", true) => (BOLD, RESET),
+                    ("em", false) => ("*", "*"),
+                    _ => ("", ""),
+                };
+                out.push_str(open);
+                inline(out, child, ansi);
+                out.push_str(close);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/day.html");
+
+    #[test]
+    fn test_render() {
+        let expected = "\
+--- Day 1: Counting Sheep ---
+
+The elves have lost count of their *sheep* again.
+Every line of your input is a pen, and each pen
+holds a number of sheep.
+
+For example:
+
+    3
+    4
+    *2*
+
+  - The first pen has 3 sheep.
+  - The second pen has 4 sheep and a very long
+    line that has to wrap.
+
+--- Part Two ---
+
+Now count only the pens with an odd number.
+";
+        assert_eq!(render(PAGE, 50, false).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let rendered = render(PAGE, 50, true).unwrap();
+        assert!(rendered.contains(&format!("their {BOLD}sheep{RESET} again")));
+    }
+
+    #[test]
+    fn test_render_missing() {
+        assert!(render("<html><body><main></main></body></html>", 50, false).is_err());
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{Answers, Result, input_hash, workspace};
+
+use crate::client::Client;
+
+/// What the site said about an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// We submitted too recently and have to wait this long.
+    Wait(Duration),
+    /// The part was already solved (or part 1 hasn't been yet).
+    WrongLevel,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wait(wait) => write!(f, "submitted too recently, wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "not the right level, is it already solved?"),
+        }
+    }
+}
+
+/// Figure out the verdict from the page we get back after submitting.
+pub fn parse_verdict(page: &str) -> Result<Verdict> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Right)
+    } else if page.contains("That's not the right answer") {
+        Ok(if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if page.contains("You gave an answer too recently") {
+        // "You have 1m 5s left to wait."
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| parse_wait(wait))
+            .unwrap_or_default();
+        Ok(Verdict::Wait(wait))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err("unable to understand the response to our answer".into())
+    }
+}
+
+fn parse_wait(wait: &str) -> Duration {
+    wait.split_whitespace()
+        .filter_map(|part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .map(Duration::from_secs)
+        .sum()
+}
+
+// Everything we've submitted, keyed like the known answers so they're tied to an input.
+type Key = (u16, u8, u8, String);
+
+/// Answers we've sent and what the site said about them, stored one per line as
+/// `<year> <day> <part> <input hash> <verdict> <answer>`. Only definite verdicts are kept.
+pub struct History {
+    path: PathBuf,
+    entries: Vec<(Key, Verdict, String)>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut entries = vec![];
+        for (i, line) in contents.lines().enumerate() {
+            let fields: Vec<&str> = line.splitn(6, ' ').collect();
+            let [year, day, part, hash, verdict, answer] = fields[..] else {
+                return Err(format!("{}:{}: expected 6 fields", path.display(), i + 1).into());
+            };
+            let verdict = match verdict {
+                "right" => Verdict::Right,
+                "wrong" => Verdict::Wrong,
+                "high" => Verdict::TooHigh,
+                "low" => Verdict::TooLow,
+                _ => return Err(format!("{}:{}: bad verdict", path.display(), i + 1).into()),
+            };
+            entries.push((
+                (year.parse()?, day.parse()?, part.parse()?, hash.to_string()),
+                verdict,
+                answer.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// Why we shouldn't send an answer, if we already know how it will go.
+    pub fn blocked(&self, key: &Key, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for (_, verdict, previous) in self.entries.iter().filter(|(k, ..)| k == key) {
+            let bound = match (number, previous.parse::<i128>()) {
+                (Some(number), Ok(bound)) => Some((number, bound)),
+                _ => None,
+            };
+            match verdict {
+                Verdict::Right => return Some(format!("already solved with {previous}")),
+                _ if previous == answer => {
+                    return Some(format!("{answer} was already submitted and was {verdict}"));
+                }
+                Verdict::TooHigh if bound.is_some_and(|(n, b)| n >= b) => {
+                    return Some(format!("{answer} is too high, {previous} already was"));
+                }
+                Verdict::TooLow if bound.is_some_and(|(n, b)| n <= b) => {
+                    return Some(format!("{answer} is too low, {previous} already was"));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, key: Key, verdict: Verdict, answer: &str) -> Result<()> {
+        let name = match verdict {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "high",
+            Verdict::TooLow => "low",
+            _ => return Ok(()),
+        };
+
+        let (year, day, part, hash) = &key;
+        let line = format!("{year} {day} {part} {hash} {name} {answer}\n");
+        let mut contents = std::fs::read_to_string(&self.path).unwrap_or_default();
+        contents.push_str(&line);
+        std::fs::write(&self.path, contents)?;
+        self.entries.push((key, verdict, answer.to_string()));
+        Ok(())
+    }
+}
+
+/// Where the submission history lives. Like the answers, it isn't committed.
+pub fn history_path() -> PathBuf {
+    workspace().join("submissions.txt")
+}
+
+/// Send an answer unless the history says it's pointless. Right answers are also recorded as known
+/// answers.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    answers: &mut Answers,
+    (year, day, part): (u16, u8, u8),
+    input: &str,
+    answer: &str,
+) -> Result<Verdict> {
+    let hash = input_hash(input);
+    let key = (year, day, part, hash.clone());
+    if let Some(reason) = history.blocked(&key, answer) {
+        return Err(format!("not submitting: {reason}").into());
+    }
+
+    let page = client.post(
+        year,
+        day,
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let verdict = parse_verdict(&page)?;
+    history.record(key, verdict.clone(), answer)?;
+    if verdict == Verdict::Right {
+        answers.record(year, day, part, &hash, answer);
+        answers.save()?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tests::{serve, temp_dir};
+
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let cases = [
+            (
+                "That's the right answer!  You are <em>one gold star</em> closer to saving Christmas.",
+                Verdict::Right,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Verdict::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Verdict::Wait(Duration::from_secs(65)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(parse_verdict(&page(message)).unwrap(), verdict);
+        }
+        assert!(parse_verdict("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = temp_dir("submit");
+        std::fs::create_dir_all(&dir).unwrap();
+        let (base, requests) = serve(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let client = Client::new(&base, "abc", &dir.join("cache"), Duration::ZERO);
+        let mut history = History::load(&dir.join("submissions.txt")).unwrap();
+        let mut answers = Answers::load(&dir.join("answers.txt")).unwrap();
+        let mut submit = |answer| {
+            submit(
+                &client,
+                &mut history,
+                &mut answers,
+                (2017, 1, 2),
+                "1122\n",
+                answer,
+            )
+        };
+
+        assert_eq!(submit("100").unwrap(), Verdict::TooHigh);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2017/day/1/answer "));
+        assert!(request.ends_with("level=2&answer=100"));
+
+        // Nothing at or above a known high bound (or repeated) gets sent.
+        assert!(submit("100").is_err());
+        assert!(submit("150").is_err());
+        assert_eq!(submit("10").unwrap(), Verdict::TooLow);
+        assert!(submit("5").is_err());
+        assert_eq!(submit("50").unwrap(), Verdict::Right);
+        assert!(submit("50").is_err());
+
+        // The history survives a reload and the right answer becomes a known answer.
+        let history = History::load(&dir.join("submissions.txt")).unwrap();
+        let key = (2017, 1, 2, input_hash("1122\n"));
+        assert!(history.blocked(&key, "60").is_some());
+        assert!(
+            history
+                .blocked(&(2017, 1, 1, key.3.clone()), "60")
+                .is_none()
+        );
+        let answers = Answers::load(&dir.join("answers.txt")).unwrap();
+        assert_eq!(
+            answers.check(2017, 1, 2, &key.3, "50"),
+            aoc_common::Status::Pass
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
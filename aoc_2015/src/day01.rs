@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("inputs/day01.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 // Convert each character to a floor change and then sum them up.
 fn p1(input: &str) -> isize {
@@ -30,15 +30,26 @@ fn p2(input: &str) -> usize {
         .unwrap()
 }
 
-pub fn main() {
-    let now = std::time::Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-    let now = std::time::Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(1, &[Part::<Day01>::part1(), Part::<Day01>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("inputs/day02.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 // A helper function to parse dimensions from a line like "2x3x4"
 fn parse_dims(line: &str) -> (usize, usize, usize) {
@@ -33,16 +33,26 @@ fn p2(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let now = std::time::Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day02;
 
-    let now = std::time::Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day02 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(2, &[Part::<Day02>::part1(), Part::<Day02>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,30 +1,15 @@
+use aoc_common::{Answer, Day, Part, Point2, Result, Solution};
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("inputs/day03.txt");
+type Point = Point2<isize>;
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Point {
-    fn new(x: isize, y: isize) -> Self {
-        Point { x, y }
-    }
-
-    fn next(self, c: char) -> Self {
-        let mut next = self;
-
-        match c {
-            '>' => next.x += 1,
-            '<' => next.x -= 1,
-            '^' => next.y += 1,
-            'v' => next.y -= 1,
-            _ => (),
-        }
-
-        next
+fn next(cur: Point, c: char) -> Point {
+    cur + match c {
+        '>' => Point::new(1, 0),
+        '<' => Point::new(-1, 0),
+        '^' => Point::new(0, 1),
+        'v' => Point::new(0, -1),
+        _ => Point::new(0, 0),
     }
 }
 
@@ -34,7 +19,7 @@ fn p1(input: &str) -> usize {
     input
         .chars()
         .scan(Point::new(0, 0), |cur, c| {
-            *cur = cur.next(c);
+            *cur = next(*cur, c);
             Some(*cur)
         })
         .chain(std::iter::once(Point::new(0, 0)))
@@ -50,7 +35,7 @@ fn p2(input: &str) -> usize {
         .enumerate()
         .scan([Point::new(0, 0), Point::new(0, 0)], |cur, (i, c)| {
             let index = i % 2;
-            cur[index] = cur[index].next(c);
+            cur[index] = next(cur[index], c);
             Some(cur[index])
         })
         .chain(std::iter::once(Point::new(0, 0)))
@@ -58,12 +43,22 @@ fn p2(input: &str) -> usize {
         .count()
 }
 
-fn main() {
-    let now = std::time::Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
 
-    let now = std::time::Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(3, &[Part::<Day03>::part1(), Part::<Day03>::part2()]);
@@ -0,0 +1,38 @@
+use aoc_common::{Answer, Day, Md5Search, Part, Result, Solution};
+
+fn find_hash(input: &str, zeros: usize) -> usize {
+    // The lowest positive number whose hash starts with enough zeros.
+    Md5Search::new(input.trim())
+        .matches(1, |hash| hash.leading_zeros() >= zeros)
+        .next()
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+fn p1(input: &str) -> usize {
+    find_hash(input, 5)
+}
+
+fn p2(input: &str) -> usize {
+    find_hash(input, 6)
+}
+
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(4, &[Part::<Day04>::part1(), Part::<Day04>::part2()]);
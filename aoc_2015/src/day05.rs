@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day05.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn p1_rules(line: &str) -> bool {
     let mut chars = line.chars();
@@ -42,16 +40,26 @@ fn p2(input: &str) -> usize {
     input.lines().filter(|line| p2_rules(line)).count()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(5, &[Part::<Day05>::part1(), Part::<Day05>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
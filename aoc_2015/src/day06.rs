@@ -1,21 +1,6 @@
-use std::time::Instant;
+use aoc_common::{Answer, Day, Part, Point2, Result, Solution};
 
-const INPUT: &str = include_str!("inputs/day06.txt");
-
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn parse(s: &str) -> Self {
-        let parts: Vec<&str> = s.split(',').collect();
-        let x = parts[0].parse().unwrap();
-        let y = parts[1].parse().unwrap();
-        Point { x, y }
-    }
-}
+type Point = Point2<usize>;
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 enum InstructionType {
@@ -49,9 +34,9 @@ impl Instruction {
         let parts: Vec<&str> = s.split_whitespace().collect();
         let (start, end) = match instruction_type {
             InstructionType::TurnOff | InstructionType::TurnOn => {
-                (Point::parse(parts[2]), Point::parse(parts[4]))
+                (parts[2].parse().unwrap(), parts[4].parse().unwrap())
             }
-            InstructionType::Toggle => (Point::parse(parts[1]), Point::parse(parts[3])),
+            InstructionType::Toggle => (parts[1].parse().unwrap(), parts[3].parse().unwrap()),
         };
         Self {
             instruction_type,
@@ -111,12 +96,22 @@ fn p2(input: &str) -> usize {
     grid.into_iter().flatten().sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day06;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(6, &[Part::<Day06>::part1(), Part::<Day06>::part2()]);
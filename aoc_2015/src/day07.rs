@@ -1,7 +1,6 @@
 use std::collections::HashMap;
-use std::time::Instant;
 
-const INPUT: &str = include_str!("inputs/day07.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 #[derive(Debug, Copy, Clone)]
 enum Operand<'a> {
@@ -115,12 +114,22 @@ fn p2(input: &str, b: u16) -> u16 {
     solve_circuit(&instructions, &mut memo, "a")
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day07;
 
-    let now = Instant::now();
-    let solution = p2(INPUT, solution);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day07 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input, p1(input)).into())
+    }
 }
+
+pub const DAY: Day = Day::new(7, &[Part::<Day07>::part1(), Part::<Day07>::part2()]);
@@ -1,4 +1,4 @@
-const INPUT: &str = include_str!("inputs/day08.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn p1(input: &str) -> usize {
     let lines = input.lines().collect::<Vec<&str>>();
@@ -42,18 +42,26 @@ fn encoded_size(s: &str) -> usize {
         .sum::<usize>()
 }
 
-use std::time::Instant;
+struct Day08;
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+impl Solution for Day08 {
+    type Input<'a> = &'a str;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(8, &[Part::<Day08>::part1(), Part::<Day08>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
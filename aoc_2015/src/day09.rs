@@ -0,0 +1,59 @@
+use aoc_common::{Answer, Day, Grid, HeldKarp, Part, Result, Solution};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+fn parse(input: &str) -> (FxHashMap<(&str, &str), usize>, Vec<&str>) {
+    let distances = input
+        .lines()
+        .flat_map(|l| {
+            let parts = l.split_ascii_whitespace().collect::<Vec<&str>>();
+            let dist = parts[4].parse().unwrap();
+            vec![((parts[0], parts[2]), dist), ((parts[2], parts[0]), dist)]
+        })
+        .collect::<FxHashMap<(&str, &str), usize>>();
+    let cities = distances
+        .keys()
+        .map(|(l, _)| *l)
+        .unique()
+        .collect::<Vec<&str>>();
+    (distances, cities)
+}
+
+// The distance between every pair of cities. Staying put is free.
+fn distances(input: &str) -> Grid<usize> {
+    let (distances, cities) = parse(input);
+    Grid::from_fn(cities.len(), cities.len(), |p| {
+        distances
+            .get(&(cities[p.y], cities[p.x]))
+            .copied()
+            .unwrap_or(0)
+    })
+}
+
+fn p1(input: &str) -> usize {
+    HeldKarp::new(&distances(input)).solve().cost
+}
+
+fn p2(input: &str) -> usize {
+    HeldKarp::new(&distances(input)).longest().solve().cost
+}
+
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(9, &[Part::<Day09>::part1(), Part::<Day09>::part2()]);
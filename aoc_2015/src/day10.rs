@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day10.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn look_and_say_len(input: &str, n: usize) -> usize {
     let mut cur = input
@@ -55,12 +53,22 @@ fn p2(input: &str) -> usize {
     look_and_say_len(input, 50)
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(10, &[Part::<Day10>::part1(), Part::<Day10>::part2()]);
@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("inputs/day11.txt");
-
 fn increment(cur: &mut [u8]) {
     let mut pos = cur.len() - 1;
     loop {
@@ -63,12 +60,22 @@ fn p2(input: &str) -> String {
     find_next_valid_password(input.trim())
 }
 
-fn main() {
-    let now = Instant::now();
-    let password = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), password);
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(&password);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(&p1(input)).into())
+    }
 }
+
+pub const DAY: Day = Day::new(11, &[Part::<Day11>::part1(), Part::<Day11>::part2()]);
@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use serde_json::Value;
 
-const INPUT: &str = include_str!("inputs/day12.txt");
-
 fn p1(input: &Value) -> i64 {
     match input {
         Value::Number(n) => n.as_i64().unwrap(),
@@ -29,13 +26,22 @@ fn p2(input: &Value) -> i64 {
     }
 }
 
-fn main() {
-    let now = Instant::now();
-    let input: Value = serde_json::from_str(INPUT).unwrap();
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Value;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(serde_json::from_str(input)?)
+    }
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(12, &[Part::<Day12>::part1(), Part::<Day12>::part2()]);
@@ -1,10 +1,7 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Grid, HeldKarp, Part, Result, Solution};
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 
-const INPUT: &str = include_str!("inputs/day13.txt");
-
 fn parse(input: &str) -> (FxHashMap<(&str, &str), isize>, Vec<&str>) {
     let happiness_index = input
         .lines()
@@ -28,27 +25,15 @@ fn parse(input: &str) -> (FxHashMap<(&str, &str), isize>, Vec<&str>) {
     (happiness_index, people)
 }
 
-fn calculate_happiness(
-    happiness_index: &FxHashMap<(&str, &str), isize>,
-    arrangement: &[&&str],
-) -> isize {
-    arrangement
-        .iter()
-        .circular_tuple_windows::<(_, _)>()
-        .map(|(&left, &right)| {
-            happiness_index.get(&(left, right)).unwrap_or(&0)
-                + happiness_index.get(&(right, left)).unwrap_or(&0)
-        })
-        .sum()
-}
-
+// Sitting next to each other changes both people's happiness, so add them together. People we
+// know nothing about don't care.
 fn max_happiness(happiness_index: &FxHashMap<(&str, &str), isize>, people: &[&str]) -> isize {
-    people
-        .iter()
-        .permutations(people.len())
-        .map(|p| calculate_happiness(happiness_index, &p))
-        .max()
-        .unwrap()
+    let happiness = Grid::from_fn(people.len(), people.len(), |p| {
+        let (left, right) = (people[p.y], people[p.x]);
+        happiness_index.get(&(left, right)).unwrap_or(&0)
+            + happiness_index.get(&(right, left)).unwrap_or(&0)
+    });
+    HeldKarp::new(&happiness).cycle().longest().solve().cost
 }
 
 fn p1(input: &str) -> isize {
@@ -63,12 +48,22 @@ fn p2(input: &str) -> isize {
     max_happiness(&happiness_index, &people)
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day13;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day13 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(13, &[Part::<Day13>::part1(), Part::<Day13>::part2()]);
@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("inputs/day14.txt");
-
 #[derive(Debug)]
 struct Reindeer {
     speed: usize,
@@ -71,12 +68,22 @@ fn p2(input: &str) -> usize {
         .unwrap()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(14, &[Part::<Day14>::part1(), Part::<Day14>::part2()]);
@@ -0,0 +1,76 @@
+use aoc_common::{Answer, Automaton, Day, Grid, Part, Point2, Result, Rule, Solution};
+
+fn lights(input: &Grid<bool>) -> Automaton {
+    Automaton::from_fn(input.width(), input.height(), Rule::LIFE, |p| input[p])
+}
+
+fn corners(input: &Grid<bool>) -> [Point2<usize>; 4] {
+    let (right, bottom) = (input.width() - 1, input.height() - 1);
+    [
+        Point2::new(0, 0),
+        Point2::new(0, bottom),
+        Point2::new(right, 0),
+        Point2::new(right, bottom),
+    ]
+}
+
+fn p1(input: &Grid<bool>) -> usize {
+    let mut lights = lights(input);
+    lights.run(100);
+    lights.population()
+}
+
+fn p2(input: &Grid<bool>) -> usize {
+    // The corners are stuck on.
+    let mut lights = corners(input)
+        .into_iter()
+        .fold(lights(input), |lights, corner| lights.pin(corner, true));
+    lights.run(100);
+    lights.population()
+}
+
+fn step(grid: &Grid<bool>, corners: &[Point2<usize>]) -> Grid<bool> {
+    Grid::from_fn(grid.width(), grid.height(), |p| {
+        let on = grid.neighbors8(p).filter(|n| grid[*n]).count();
+        matches!((grid[p], on), (true, 2 | 3) | (false, 3)) || corners.contains(&p)
+    })
+}
+
+// Step the grid a cell at a time.
+fn animate(input: &Grid<bool>, corners: &[Point2<usize>]) -> usize {
+    let mut grid = input.clone();
+    for _ in 0..100 {
+        grid = step(&grid, corners);
+    }
+    grid.count(&true)
+}
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(Grid::from_bytes(input)?.map(|&b| b == b'#'))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    18,
+    &[
+        Part::<Day18>::part1(),
+        Part::<Day18>::part2(),
+        Part::<Day18>::variant(1, "p1_grid", |input| Ok(animate(input, &[]).into())),
+        Part::<Day18>::variant(2, "p2_grid", |input| {
+            Ok(animate(input, &corners(input)).into())
+        }),
+    ],
+);
@@ -1,6 +1,6 @@
-use std::{collections::HashSet, time::Instant};
+use std::collections::HashSet;
 
-const INPUT: &str = include_str!("inputs/day19.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn p1(input: &str) -> usize {
     let (replacements, molecule) = input.split_once("\n\n").unwrap();
@@ -31,12 +31,22 @@ fn p2(input: &str) -> usize {
     molecule_count - rn - ar - y * 2 - 1
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day19;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day19 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(19, &[Part::<Day19>::part1(), Part::<Day19>::part2()]);
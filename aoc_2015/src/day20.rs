@@ -0,0 +1,73 @@
+use aoc_common::{Answer, Day, Part, Result, Solution, divisor_sums, divisor_sums_within};
+
+// Each elf delivers to the houses that are multiples of its number, so a house gets presents
+// from each of its divisors. House n gets at least n * per_elf presents from its own elf, so we
+// never have to look past input / per_elf + 1. Even the most divisible houses only get a handful of
+// times more than that, so start looking at an eighth of the way there and double until we find
+// it.
+fn first_house(input: usize, sums: impl Fn(usize) -> Vec<u64>, per_elf: u64) -> usize {
+    let max = input / per_elf as usize + 1;
+    let mut limit = max / 8 + 1;
+    loop {
+        limit = limit.min(max);
+        // Up to and including house `limit`.
+        if let Some(house) = sums(limit + 1)
+            .iter()
+            .position(|&sum| sum * per_elf >= input as u64)
+        {
+            return house;
+        }
+        limit *= 2;
+    }
+}
+
+fn p1(input: &str) -> usize {
+    let input = input.trim().parse::<usize>().unwrap();
+    first_house(input, divisor_sums, 10)
+}
+
+fn p2(input: &str) -> usize {
+    // The elves get lazy and stop after 50 houses.
+    let input = input.trim().parse::<usize>().unwrap();
+    first_house(input, |limit| divisor_sums_within(limit, 50), 11)
+}
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(20, &[Part::<Day20>::part1(), Part::<Day20>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1("70"), 4);
+        assert_eq!(p1("150"), 8);
+
+        // The last house it could be.
+        assert_eq!(p1("15"), 2);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2("12"), 2);
+        assert_eq!(p2("100"), 6);
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 #[derive(Debug)]
 struct Player {
@@ -174,8 +174,6 @@ impl Iterator for ItemCombinations {
     }
 }
 
-const INPUT: &str = include_str!("inputs/day21.txt");
-
 fn p1(input: &str) -> i32 {
     let boss = Player::parse(input);
     ItemCombinations::new()
@@ -202,12 +200,22 @@ fn p2(input: &str) -> i32 {
         .unwrap()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(21, &[Part::<Day21>::part1(), Part::<Day21>::part2()]);
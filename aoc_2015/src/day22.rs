@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day22.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution, WeightedStateSpace, dijkstra};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 struct State {
@@ -121,36 +119,50 @@ impl State {
     }
 }
 
+// Every turn of the fight, where the cost of a move is the mana spent.
+struct Fight {
+    hard: bool,
+}
+
+impl WeightedStateSpace for Fight {
+    type State = State;
+    type Cost = i32;
+
+    fn successors(&self, state: &State) -> impl IntoIterator<Item = (State, i32)> {
+        state.neighbors(self.hard)
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.health > 0 && state.boss_health <= 0
+    }
+}
+
 fn p1(input: &str) -> i32 {
     let start = State::new(input);
-
-    let (_, cost) = pathfinding::directed::dijkstra::dijkstra(
-        &start,
-        |s| s.neighbors(false),
-        |s| s.health > 0 && s.boss_health <= 0,
-    )
-    .unwrap();
-    cost
+    dijkstra(&Fight { hard: false }, start).unwrap().cost
 }
 
 fn p2(input: &str) -> i32 {
     let start = State::new(input);
-
-    let (_, cost) = pathfinding::directed::dijkstra::dijkstra(
-        &start,
-        |s| s.neighbors(true),
-        |s| s.health > 0 && s.boss_health <= 0,
-    )
-    .unwrap();
-    cost
+    dijkstra(&Fight { hard: true }, start).unwrap().cost
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(22, &[Part::<Day22>::part1(), Part::<Day22>::part2()]);
@@ -1,9 +1,6 @@
-use std::{
-    ops::{Index, IndexMut},
-    time::Instant,
-};
+use std::ops::{Index, IndexMut};
 
-const INPUT: &str = include_str!("inputs/day23.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum Register {
@@ -143,12 +140,22 @@ fn p2(input: &str) -> usize {
     machine.b
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day23;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day23 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(23, &[Part::<Day23>::part1(), Part::<Day23>::part2()]);
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day25.txt");
+use aoc_common::{Answer, Day, ModArith, Part, Result, Solution};
 
 pub fn p1(input: &str) -> u64 {
     let parts = input.split_whitespace().collect::<Vec<&str>>();
@@ -18,31 +16,22 @@ pub fn p1(input: &str) -> u64 {
     let multiplier = 252533;
     let modulus = 33554393;
 
-    let pow = modular_exponentiation(multiplier, steps, modulus);
+    let pow = multiplier.pow_mod(steps, modulus);
     (start * pow) % modulus
 }
 
-fn modular_exponentiation(base: u64, mut exponent: u64, modulus: u64) -> u64 {
-    let mut result = 1;
-    let mut b = base;
+struct Day25;
 
-    while exponent > 0 {
-        // If the current bit of the exponent is 1 (i.e., exponent is odd)
-        if exponent % 2 == 1 {
-            result = (result * b) % modulus;
-        }
+impl Solution for Day25 {
+    type Input<'a> = &'a str;
 
-        // Square the base and take the modulus
-        b = (b * b) % modulus;
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
 
-        // Move to the next bit (integer division by 2)
-        exponent /= 2;
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
     }
-    result
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-}
+pub const DAY: Day = Day::new(25, &[Part::<Day25>::part1()]);
@@ -0,0 +1,58 @@
+use aoc_common::Year;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const YEAR: Year = Year::new(
+    2015,
+    &[
+        day01::DAY,
+        day02::DAY,
+        day03::DAY,
+        day04::DAY,
+        day05::DAY,
+        day06::DAY,
+        day07::DAY,
+        day08::DAY,
+        day09::DAY,
+        day10::DAY,
+        day11::DAY,
+        day12::DAY,
+        day13::DAY,
+        day14::DAY,
+        day15::DAY,
+        day16::DAY,
+        day17::DAY,
+        day18::DAY,
+        day19::DAY,
+        day20::DAY,
+        day21::DAY,
+        day22::DAY,
+        day23::DAY,
+        day24::DAY,
+        day25::DAY,
+    ],
+);
@@ -0,0 +1,53 @@
+use aoc_common::{Answer, Day, Part, Result, Solution};
+
+fn parse(input: &str) -> impl Iterator<Item = &str> {
+    // TODO did you trim today?
+    input.trim().lines()
+}
+
+fn p1(input: &str) -> usize {
+    let input = parse(input);
+    input.count()
+}
+
+fn p2(input: &str) -> usize {
+    let input = parse(input);
+    input.count()
+}
+
+struct [STRUCT];
+
+impl Solution for [STRUCT] {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new([DAY], &[Part::<[STRUCT]>::part1(), Part::<[STRUCT]>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/[NAME]-sample.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 0);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(INPUT), 0);
+    }
+}
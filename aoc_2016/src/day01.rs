@@ -1,50 +1,17 @@
-use std::{
-    error::Error,
-    ops::{AddAssign, Mul},
-    time::Instant,
-};
+use std::error::Error;
 
+use aoc_common::{Answer, Day, Part, Point2, Solution};
 use rustc_hash::{FxBuildHasher, FxHashSet};
 
 // isize vs i32 --> i32 is marginally faster
 type Int = i32;
+type Point = Point2<Int>;
 
-const INPUT: &str = include_str!("inputs/day01.txt");
-
-#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
-struct Point {
-    x: Int,
-    y: Int,
-}
-
-impl Point {
-    fn new(x: Int, y: Int) -> Self {
-        Self { x, y }
-    }
-}
-
-impl AddAssign for Point {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
-impl Mul<Int> for Point {
-    type Output = Point;
-
-    fn mul(self, rhs: Int) -> Self::Output {
-        Self::new(self.x * rhs, self.y * rhs)
-    }
-}
-
-/// Allow for change direction via add_assign for char.
-impl AddAssign<char> for Point {
-    fn add_assign(&mut self, rhs: char) {
-        *self = match rhs {
-            'R' => Point::new(self.y, -self.x),
-            _ => Point::new(-self.y, self.x),
-        };
+// Change direction based on the turn in the instruction.
+fn turn(direction: Point, rhs: char) -> Point {
+    match rhs {
+        'R' => direction.rotate_right(),
+        _ => direction.rotate_left(),
     }
 }
 
@@ -83,12 +50,12 @@ fn p1(input: &Input) -> Int {
     let end = input
         .iter()
         .fold(State::default(), |mut state, &(direction, distance)| {
-            state.direction += direction;
+            state.direction = turn(state.direction, direction);
             state.location += state.direction * distance;
             state
         });
     // Taxi-cab distance from (0, 0)
-    end.location.x.abs() + end.location.y.abs()
+    end.location.manhattan(&Point::default())
 }
 
 fn p2(input: &Input) -> Int {
@@ -97,7 +64,7 @@ fn p2(input: &Input) -> Int {
     let mut seen = FxHashSet::with_capacity_and_hasher(256, FxBuildHasher);
     let mut cur = State::default();
     'outer: for &(direction, distance) in input {
-        cur.direction += direction;
+        cur.direction = turn(cur.direction, direction);
         for _ in 0..distance {
             cur.location += cur.direction;
             if !seen.insert(cur.location) {
@@ -106,18 +73,25 @@ fn p2(input: &Input) -> Int {
         }
     }
     // Taxi-cab distance from (0, 0)
-    cur.location.x.abs() + cur.location.y.abs()
+    cur.location.manhattan(&Point::default())
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT)?;
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day01;
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day01 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(1, &[Part::<Day01>::part1(), Part::<Day01>::part2()]);
@@ -1,30 +1,13 @@
-use std::{ops::Add, time::Instant};
-
-const INPUT: &str = include_str!("inputs/day02.txt");
+use aoc_common::{Answer, Day, Part, Point2, Result, Solution};
 
 type Int = i32;
+type Point = Point2<Int>;
 type Input<'a> = Vec<Vec<char>>;
 
 fn parse_input(input: &'_ str) -> Input<'_> {
     input.lines().map(|l| l.trim().chars().collect()).collect()
 }
 
-#[derive(Clone, Copy)]
-struct Point {
-    x: Int,
-    y: Int,
-}
-
-impl Add for Point {
-    type Output = Point;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
 const DELTAS: [Point; 4] = [
     Point { x: 0, y: -1 },
     Point { x: 0, y: 1 },
@@ -41,12 +24,6 @@ fn delta(direction: char) -> Point {
     }
 }
 
-impl Point {
-    fn new(x: Int, y: Int) -> Self {
-        Self { x, y }
-    }
-}
-
 fn find_code(pad: &[Vec<char>], input: &Input, x: Int, y: Int) -> String {
     let mut code = String::with_capacity(pad.len());
     let mut cur = Point::new(x, y);
@@ -92,13 +69,22 @@ fn p2(input: &Input) -> String {
     find_code(&pad, input, 0, 2)
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(2, &[Part::<Day02>::part1(), Part::<Day02>::part2()]);
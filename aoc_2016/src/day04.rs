@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use itertools::Itertools;
 
-const INPUT: &str = include_str!("inputs/day04.txt");
-
 #[derive(Debug)]
 struct RoomInfo<'a> {
     name: &'a str,
@@ -68,13 +65,22 @@ fn p2(input: &Input) -> String {
         .to_string()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(4, &[Part::<Day04>::part1(), Part::<Day04>::part2()]);
@@ -0,0 +1,61 @@
+use aoc_common::{Answer, Day, Md5Hash, Md5Search, Part, Result, Solution};
+
+type Input<'a> = &'a str;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input.trim()
+}
+
+// The hashes whose hex starts with 00000, in order.
+fn interesting(search: &Md5Search) -> impl Iterator<Item = Md5Hash> + '_ {
+    search
+        .matches(0, |hash| hash.leading_zeros() >= 5)
+        .map(|(_, hash)| hash)
+}
+
+fn hex_digit(nibble: u8) -> char {
+    std::char::from_digit(nibble as u32, 16).unwrap()
+}
+
+fn p1(input: &Input) -> String {
+    // The 6th letter of each is the next letter of the password.
+    interesting(&Md5Search::new(input))
+        .take(8)
+        .map(|hash| hex_digit(hash.nibble(5)))
+        .collect()
+}
+
+fn p2(input: &Input) -> String {
+    // The 6th letter is the position and the 7th is what goes there, the first time it shows up.
+    let mut password = [' '; 8];
+    let search = Md5Search::new(input);
+    let mut hashes = interesting(&search);
+    while password.contains(&' ') {
+        let hash = hashes.next().unwrap();
+        let n = hash.nibble(5) as usize;
+        if n < 8 && password[n] == ' ' {
+            password[n] = hex_digit(hash.nibble(6));
+        }
+    }
+    password.iter().collect()
+}
+
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(5, &[Part::<Day05>::part1(), Part::<Day05>::part2()]);
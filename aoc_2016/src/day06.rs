@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use rustc_hash::FxHashMap;
 
-const INPUT: &str = include_str!("inputs/day06.txt");
-
 type Input<'a> = Vec<FxHashMap<char, usize>>;
 
 fn parse_input(input: &'_ str) -> Input<'_> {
@@ -41,13 +38,22 @@ where
         .collect()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Input<'a>;
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(6, &[Part::<Day06>::part1(), Part::<Day06>::part2()]);
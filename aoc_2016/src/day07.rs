@@ -1,9 +1,6 @@
-use std::time::Instant;
-
+use aoc_common::{Answer, Day, Part, Result, Solution};
 use rustc_hash::FxHashSet;
 
-const INPUT: &str = include_str!("inputs/day07.txt");
-
 type Input<'a> = Vec<(Vec<&'a str>, Vec<&'a str>)>;
 
 fn split_brackets(mut input: &str) -> (Vec<&str>, Vec<&str>) {
@@ -68,13 +65,22 @@ fn p2(input: &Input) -> usize {
         .count()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(7, &[Part::<Day07>::part1(), Part::<Day07>::part2()]);
@@ -1,6 +1,6 @@
-use std::{fmt::Display, time::Instant};
+use std::fmt::Display;
 
-const INPUT: &str = include_str!("inputs/day08.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 type Input<'a> = Vec<Instruction>;
 
@@ -38,7 +38,6 @@ fn parse_input(input: &'_ str) -> Input<'_> {
 
 const WIDTH: usize = 50;
 const HEIGHT: usize = 6;
-const LETTER_WIDTH: usize = 5;
 
 struct Screen {
     pixels: [[char; WIDTH]; HEIGHT],
@@ -94,39 +93,6 @@ impl Screen {
             row[column] = *val;
         }
     }
-
-    fn decode_message(&self) -> String {
-        let mut message = String::new();
-
-        for start in (0..WIDTH).step_by(LETTER_WIDTH) {
-            if start + LETTER_WIDTH > WIDTH {
-                break;
-            }
-
-            let mut glyph = String::new();
-            let mut has_lit_pixel = false;
-
-            for row in 0..HEIGHT {
-                let slice = &self.pixels[row][start..start + LETTER_WIDTH];
-                if slice.contains(&'#') {
-                    has_lit_pixel = true;
-                }
-                glyph.extend(slice);
-                if row + 1 < HEIGHT {
-                    glyph.push('\n');
-                }
-            }
-
-            if !has_lit_pixel {
-                continue;
-            }
-
-            let letter = decode_glyph(&glyph).unwrap_or('?');
-            message.push(letter);
-        }
-
-        message
-    }
 }
 
 fn p1(input: &Input) -> usize {
@@ -135,33 +101,28 @@ fn p1(input: &Input) -> usize {
     screen.on()
 }
 
-fn p2(input: &Input) -> String {
+fn p2(input: &Input) -> Answer {
     let mut screen = Screen::new();
     input.iter().for_each(|i| screen.apply(i));
-    screen.decode_message()
+    Answer::ocr(&screen.to_string())
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day08;
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-}
+impl Solution for Day08 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-fn decode_glyph(pattern: &str) -> Option<char> {
-    match pattern {
-        "####.\n#....\n###..\n#....\n#....\n####." => Some('E'),
-        ".##..\n#..#.\n#..#.\n#..#.\n#..#.\n.##.." => Some('O'),
-        ".##..\n#..#.\n#..#.\n####.\n#..#.\n#..#." => Some('A'),
-        "###..\n#..#.\n#..#.\n###..\n#.#..\n#..#." => Some('R'),
-        ".##..\n#..#.\n#....\n#.##.\n#..#.\n.###." => Some('G'),
-        "###..\n#..#.\n#..#.\n###..\n#....\n#...." => Some('P'),
-        "#..#.\n#..#.\n####.\n#..#.\n#..#.\n#..#." => Some('H'),
-        "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.." => Some('Y'),
-        _ => None,
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input))
     }
 }
+
+pub const DAY: Day = Day::new(8, &[Part::<Day08>::part1(), Part::<Day08>::part2()]);
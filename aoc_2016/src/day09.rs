@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day09.txt");
+use aoc_common::{Answer, Day, Part, Solution};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 type Input<'a> = &'a str;
@@ -45,15 +43,22 @@ fn p2(input: Input) -> Result<usize> {
     get_length(input, true)
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(input)?;
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Input<'a>;
 
-    let now = Instant::now();
-    let solution = p2(input)?;
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input)?.into())
+    }
 }
+
+pub const DAY: Day = Day::new(9, &[Part::<Day09>::part1(), Part::<Day09>::part2()]);
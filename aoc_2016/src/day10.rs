@@ -1,9 +1,8 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
+use aoc_common::{Answer, Day, Part, Solution};
 use rustc_hash::FxHashMap;
 
-const INPUT: &str = include_str!("inputs/day10.txt");
-
 enum Location {
     Output(usize),
     Bot(usize),
@@ -136,15 +135,22 @@ fn p2(input: &Input) -> usize {
         .product()
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT)?;
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Input<'a>;
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(10, &[Part::<Day10>::part1(), Part::<Day10>::part2()]);
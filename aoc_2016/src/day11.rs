@@ -1,9 +1,9 @@
-use std::{collections::VecDeque, hash::Hash, time::Instant};
+use std::hash::Hash;
 
+use aoc_common::{Answer, Day, Part, Result, Solution, StateSpace, bfs};
 use itertools::Itertools;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 
-const INPUT: &str = include_str!("inputs/day11.txt");
 const FLOOR_COUNT: usize = 4;
 const TOP_FLOOR: usize = FLOOR_COUNT - 1;
 
@@ -193,30 +193,27 @@ fn parse_input(input: &'_ str) -> Input<'_> {
     state
 }
 
-fn bfs(input: &Input) -> usize {
-    let mut visited: FxHashSet<State> = FxHashSet::default();
-    let mut frontier: VecDeque<(State, usize)> = VecDeque::new();
+// The floors of the facility. Every state is a move of the elevator with one or two items.
+struct Facility;
 
-    visited.insert(input.clone());
-    frontier.push_back((input.clone(), 0));
+impl StateSpace for Facility {
+    type State = State;
 
-    while let Some((state, count)) = frontier.pop_front() {
-        if state.done() {
-            return count;
-        }
+    fn successors(&self, state: &State) -> impl IntoIterator<Item = State> {
+        state.successors()
+    }
 
-        for successor in state.successors() {
-            if visited.insert(successor.clone()) {
-                frontier.push_back((successor, count + 1));
-            }
-        }
+    fn is_goal(&self, state: &State) -> bool {
+        state.done()
     }
+}
 
-    panic!("not found!")
+fn steps(input: &Input) -> usize {
+    bfs(&Facility, input.clone()).expect("not found!").cost
 }
 
 fn p1(input: &Input) -> usize {
-    bfs(input)
+    steps(input)
 }
 
 fn p2(input: &Input) -> usize {
@@ -224,16 +221,25 @@ fn p2(input: &Input) -> usize {
     input.equipment.push((0, 0));
     input.equipment.push((0, 0));
     input.normalize();
-    bfs(&input)
+    steps(&input)
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Input<'a>;
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(11, &[Part::<Day11>::part1(), Part::<Day11>::part2()]);
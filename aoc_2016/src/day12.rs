@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day12.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 #[derive(Debug)]
 enum Value {
@@ -91,26 +89,52 @@ fn p2(input: &Input) -> isize {
     sim(input, 1)
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    let now = Instant::now();
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = code(false);
-    println!("p1-code {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
 
-    let now = Instant::now();
-    let solution = code(true);
-    println!("p2-code {:?} {}", now.elapsed(), solution);
+pub const DAY: Day = Day::new(
+    12,
+    &[
+        Part::<Day12>::part1(),
+        Part::<Day12>::part2(),
+        Part::<Day12>::variant(1, "p1_code", |input| Ok(code(input, false)?.into())),
+        Part::<Day12>::variant(2, "p2_code", |input| Ok(code(input, true)?.into())),
+    ],
+);
+
+// Every literal copied into a register, in order.
+fn literals(input: &Input, register: usize) -> Vec<isize> {
+    input
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Cpy(Value::Literal(n), r) if *r == register => Some(*n),
+            _ => None,
+        })
+        .collect()
 }
 
-fn code(p2: bool) -> isize {
+// The program worked out by hand. The numbers differ between inputs, so they're pulled out of the
+// first and last literals copied into c and d.
+fn code(input: &Input, p2: bool) -> Result<isize> {
+    let (&[d0, .., d1], &[c0, .., c1]) = (&literals(input, 3)[..], &literals(input, 2)[..]) else {
+        return Err("not the program this was worked out from".into());
+    };
+
     // cpy 1 a
     // cpy 1 b
     // cpy 26 d
@@ -118,15 +142,15 @@ fn code(p2: bool) -> isize {
     // jnz 1 5
     let mut a = 1;
     let mut b = 1;
-    let mut d = 26;
+    let mut d = d0;
 
-    // Part 2 d += 7;
+    // Part 2 d += c;
     // cpy 7 c
     // inc d
     // dec c
     // jnz c -2
     if p2 {
-        d += 7;
+        d += c0;
     }
 
     // cpy a c
@@ -149,5 +173,5 @@ fn code(p2: bool) -> isize {
     // jnz d -2
     // dec c
     // jnz c -5
-    a + 11 * 19
+    Ok(a + d1 * c1)
 }
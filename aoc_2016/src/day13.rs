@@ -0,0 +1,113 @@
+use aoc_common::{
+    Answer, Day, Part, Point2, Result, Solution, StateSpace, WeightedStateSpace, astar, bfs,
+    bidirectional_bfs, reachable,
+};
+
+type Input<'a> = isize;
+type Point = Point2<isize>;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input.trim().parse().unwrap()
+}
+
+const START: Point = Point::new(1, 1);
+const END: Point = Point::new(31, 39);
+
+// The office floor for a favorite number.
+struct Office {
+    favorite: isize,
+}
+
+impl Office {
+    fn wall(&self, &Point { x, y }: &Point) -> bool {
+        let n = x * x + 3 * x + 2 * x * y + y + y * y + self.favorite;
+        !n.count_ones().is_multiple_of(2)
+    }
+}
+
+impl StateSpace for Office {
+    type State = Point;
+
+    fn successors(&self, point: &Point) -> impl IntoIterator<Item = Point> {
+        point
+            .neighbors4()
+            .filter(|Point { x, y }| *x >= 0 && *y >= 0)
+            .filter(|p| !self.wall(p))
+    }
+
+    fn is_goal(&self, point: &Point) -> bool {
+        *point == END
+    }
+}
+
+// Moves all cost one, so the manhattan distance never overestimates.
+impl WeightedStateSpace for Office {
+    type State = Point;
+    type Cost = isize;
+
+    fn successors(&self, point: &Point) -> impl IntoIterator<Item = (Point, isize)> {
+        StateSpace::successors(self, point)
+            .into_iter()
+            .map(|p| (p, 1))
+    }
+
+    fn is_goal(&self, point: &Point) -> bool {
+        *point == END
+    }
+
+    fn heuristic(&self, point: &Point) -> isize {
+        point.manhattan(&END)
+    }
+}
+
+fn p1(input: &Input) -> usize {
+    bfs(&Office { favorite: *input }, START)
+        .expect("not found")
+        .cost
+}
+
+fn p1_astar(input: &Input) -> usize {
+    astar(&Office { favorite: *input }, START)
+        .expect("not found")
+        .cost as usize
+}
+
+fn p1_bidirectional(input: &Input) -> usize {
+    bidirectional_bfs(&Office { favorite: *input }, START, END)
+        .expect("not found")
+        .cost
+}
+
+fn p2(input: &Input) -> usize {
+    reachable(&Office { favorite: *input }, START, 50).len()
+}
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    13,
+    &[
+        Part::<Day13>::part1(),
+        Part::<Day13>::part2(),
+        Part::<Day13>::variant(1, "p1_astar", |input| Ok(p1_astar(input).into())),
+        Part::<Day13>::variant(1, "p1_bidirectional", |input| {
+            Ok(p1_bidirectional(input).into())
+        }),
+    ],
+);
@@ -0,0 +1,66 @@
+use aoc_common::{Answer, Day, Md5Search, Part, Result, Solution};
+
+type Input<'a> = &'a str;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input.trim()
+}
+
+fn find_nth(input: &Input, n: usize, extra_hashes: usize) -> usize {
+    // Each hash gets looked at by up to a thousand keys before it, so keep them around along with
+    // which digits they have five of in a row.
+    let search = Md5Search::new(input).stretch(extra_hashes);
+    let mut cache = search.cache();
+    let mut fives = vec![];
+
+    // A key has three of a digit in a row, and one of the next thousand has five of that digit.
+    let mut found = 0;
+    for id in 0.. {
+        while fives.len() <= id + 1000 {
+            let hash = cache.get(fives.len());
+            fives.push(hash.runs(5).fold(0u16, |digits, d| digits | 1 << d));
+        }
+        let Some(triple) = cache.get(id).runs(3).next() else {
+            continue;
+        };
+        if fives[id + 1..=id + 1000]
+            .iter()
+            .any(|&d| d & 1 << triple != 0)
+        {
+            found += 1;
+            if found == n {
+                return id;
+            }
+        }
+    }
+
+    panic!("not found")
+}
+
+fn p1(input: &Input) -> usize {
+    find_nth(input, 64, 0)
+}
+
+fn p2(input: &Input) -> usize {
+    find_nth(input, 64, 2016)
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(14, &[Part::<Day14>::part1(), Part::<Day14>::part2()]);
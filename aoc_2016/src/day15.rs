@@ -0,0 +1,88 @@
+use aoc_common::{Answer, Day, Part, Result, Solution, crt};
+
+type Input<'a> = Vec<(usize, usize)>;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input
+        .lines()
+        .map(|l| {
+            let parts = l
+                .trim_end_matches('.')
+                .split_whitespace()
+                .collect::<Vec<_>>();
+            (parts[11].parse().unwrap(), parts[3].parse().unwrap())
+        })
+        .collect()
+}
+
+/// Given discs as Vec<(start_pos, positions)>, compute earliest t (if any)
+fn solve(discs: &[(usize, usize)]) -> Option<usize> {
+    // Disc i (1-based) is at slot 0 when t + i + p ≡ 0 (mod m).
+    let congruences = discs
+        .iter()
+        .enumerate()
+        .map(|(i, &(p, m))| (-((p + i + 1) as i128), m as i128));
+    crt(congruences).map(|(t, _)| t as usize)
+}
+
+fn p1(input: &Input) -> usize {
+    (0..)
+        .find(|time| {
+            input
+                .iter()
+                .enumerate()
+                .all(|(i, (pos, modulus))| ((time + pos + i + 1) % modulus) == 0)
+        })
+        .unwrap()
+}
+
+fn p2(input: &Input) -> usize {
+    let mut input = input.clone();
+    input.push((0, 11));
+    (0..)
+        .find(|time| {
+            input
+                .iter()
+                .enumerate()
+                .all(|(i, (pos, modulus))| ((time + pos + i + 1) % modulus) == 0)
+        })
+        .unwrap()
+}
+
+fn p2_crt(input: &Input) -> Option<usize> {
+    let mut discs = input.clone();
+    discs.push((0, 11));
+    solve(&discs)
+}
+
+struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    15,
+    &[
+        Part::<Day15>::part1(),
+        Part::<Day15>::part2(),
+        Part::<Day15>::variant(1, "p1_crt", |input| {
+            Ok(solve(input).ok_or("no solution")?.into())
+        }),
+        Part::<Day15>::variant(2, "p2_crt", |input| {
+            Ok(p2_crt(input).ok_or("no solution")?.into())
+        }),
+    ],
+);
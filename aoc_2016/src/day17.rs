@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Answer, Day, Md5Search, Part, Result, Solution, StateSpace, bfs};
+
+type Input<'a> = &'a str;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input.trim()
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct State {
+    x: usize,
+    y: usize,
+    directions: Vec<u8>,
+}
+
+// Doors are open for b through f.
+fn open(nibble: u8) -> bool {
+    nibble > 0xa
+}
+
+impl State {
+    fn new(x: usize, y: usize) -> Self {
+        Self {
+            x,
+            y,
+            directions: Vec::new(),
+        }
+    }
+
+    fn neighbors(&self, passcode: &Md5Search) -> Vec<Self> {
+        // Get the md5 of the passcode and the path so far.
+        let hash = passcode.hash_with(&self.directions);
+
+        let mut neighbors = Vec::new();
+
+        // Up
+        if self.y > 0 && open(hash.nibble(0)) {
+            let mut directions = self.directions.clone();
+            directions.push(b'U');
+            neighbors.push(Self {
+                x: self.x,
+                y: self.y - 1,
+                directions,
+            });
+        }
+
+        // Down
+        if self.y < 3 && open(hash.nibble(1)) {
+            let mut directions = self.directions.clone();
+            directions.push(b'D');
+            neighbors.push(Self {
+                x: self.x,
+                y: self.y + 1,
+                directions,
+            });
+        }
+
+        // Left
+        if self.x > 0 && open(hash.nibble(2)) {
+            let mut directions = self.directions.clone();
+            directions.push(b'L');
+            neighbors.push(Self {
+                x: self.x - 1,
+                y: self.y,
+                directions,
+            });
+        }
+
+        // Right
+        if self.x < 3 && open(hash.nibble(3)) {
+            let mut directions = self.directions.clone();
+            directions.push(b'R');
+            neighbors.push(Self {
+                x: self.x + 1,
+                y: self.y,
+                directions,
+            });
+        }
+
+        neighbors
+    }
+}
+
+// The 4x4 grid of rooms. The path taken is part of the state, so there's no revisiting a state.
+struct Vault {
+    passcode: Md5Search,
+}
+
+impl Vault {
+    fn new(passcode: &str) -> Self {
+        Self {
+            passcode: Md5Search::new(passcode),
+        }
+    }
+}
+
+impl StateSpace for Vault {
+    type State = State;
+
+    fn successors(&self, state: &State) -> impl IntoIterator<Item = State> {
+        // Once we reach the vault, we're done.
+        match self.is_goal(state) {
+            true => vec![],
+            false => state.neighbors(&self.passcode),
+        }
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.x == 3 && state.y == 3
+    }
+}
+
+fn shortest_path(passcode: &str) -> String {
+    let found = bfs(&Vault::new(passcode), State::new(0, 0)).unwrap();
+    found.goal().directions.iter().map(|&b| b as char).collect()
+}
+
+fn p1(input: &Input) -> String {
+    shortest_path(input)
+}
+
+fn longest_path(passcode: &str) -> usize {
+    let vault = Vault::new(passcode);
+    let mut frontier = VecDeque::new();
+    frontier.push_back(State::new(0, 0));
+
+    // You don't need the visited hash here because the MD5 hash of the path implies uniqueness.
+
+    let mut max = 0;
+    while let Some(state) = frontier.pop_front() {
+        if vault.is_goal(&state) {
+            max = max.max(state.directions.len());
+        }
+        frontier.extend(vault.successors(&state));
+    }
+
+    max
+}
+
+fn p2(input: &Input) -> usize {
+    longest_path(input)
+}
+
+struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(17, &[Part::<Day17>::part1(), Part::<Day17>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(shortest_path("ihgpwlah"), "DDRRRD");
+        assert_eq!(shortest_path("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+    }
+
+    #[test]
+    fn test_longest_path() {
+        assert_eq!(longest_path("ihgpwlah"), 370);
+        assert_eq!(longest_path("ulqzkmiv"), 830);
+    }
+}
@@ -0,0 +1,98 @@
+use std::iter::once;
+
+use aoc_common::{Answer, Automaton, Day, Part, Result, Rule, Solution};
+use itertools::Itertools;
+
+type Input<'a> = Vec<char>;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input.trim().chars().collect()
+}
+
+fn next<'a>(input: &'a Input) -> Input<'a> {
+    once('.')
+        .chain(input.iter().cloned())
+        .chain(once('.'))
+        .tuple_windows()
+        .map(|(l, _, r)| match (l, r) {
+            ('.', '^') => '^',
+            ('^', '.') => '^',
+            _ => '.',
+        })
+        .collect()
+}
+
+fn next_n(input: &Input, n: usize) -> usize {
+    let mut input = input.clone();
+    let mut count = input.iter().filter(|c| **c == '.').count();
+    for _ in 0..n - 1 {
+        input = next(&input);
+        count += input.iter().filter(|c| **c == '.').count();
+    }
+
+    count
+}
+
+fn next_n_bits(input: &Input, n: usize) -> usize {
+    // A tile is a trap when exactly one of the tiles to its left and right is, which is rule 90
+    // with each row the next step.
+    let mut traps = Automaton::from_fn(input.len(), 1, Rule::Elementary(90), |p| input[p.x] == '^');
+    let mut count = 0;
+    for _ in 0..n {
+        count += traps.width() - traps.population();
+        traps.step();
+    }
+    count
+}
+
+fn p1(input: &Input) -> usize {
+    next_n_bits(input, 40)
+}
+
+fn p2(input: &Input) -> usize {
+    next_n_bits(input, 400000)
+}
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    18,
+    &[
+        Part::<Day18>::part1(),
+        Part::<Day18>::part2(),
+        Part::<Day18>::variant(1, "p1_chars", |input| Ok(next_n(input, 40).into())),
+        Part::<Day18>::variant(2, "p2_chars", |input| Ok(next_n(input, 400000).into())),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = ".^^.^.^^^^".chars().collect();
+        assert_eq!(next_n_bits(&input, 10), 38);
+    }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(".^^.^.^^^^").unwrap();
+    }
+}
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day19.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 type Input<'a> = usize;
 
@@ -29,13 +27,22 @@ fn p2(input: Input) -> usize {
     }
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input(INPUT);
-    let solution = p1(input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(*input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(*input).into())
+    }
 }
+
+pub const DAY: Day = Day::new(19, &[Part::<Day19>::part1(), Part::<Day19>::part2()]);
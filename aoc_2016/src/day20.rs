@@ -0,0 +1,40 @@
+use aoc_common::{Answer, Day, IntervalSet, Part, Result, Solution};
+
+type Input<'a> = IntervalSet<u32>;
+
+fn parse_input(input: &'_ str) -> Input<'_> {
+    input
+        .trim()
+        .lines()
+        .map(|l| l.split_once('-').unwrap())
+        .map(|(a, b)| a.parse::<u32>().unwrap()..=b.parse::<u32>().unwrap())
+        .collect()
+}
+
+fn p1(input: &Input) -> u32 {
+    *input.gaps(0..=u32::MAX).next().unwrap().start()
+}
+
+fn p2(input: &Input) -> u64 {
+    input.complement(0..=u32::MAX).len() as u64
+}
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(20, &[Part::<Day20>::part1(), Part::<Day20>::part2()]);
@@ -1,6 +1,6 @@
-use std::{error::Error, time::Instant};
+use std::error::Error;
 
-const INPUT: &str = include_str!("inputs/day21.txt");
+use aoc_common::{Answer, Day, Part, Solution};
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
@@ -143,15 +143,22 @@ fn p2(input: &Input) -> Result<String> {
     Ok(password.iter().collect::<String>())
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT)?;
-    let solution = p1(&input)?;
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day21;
 
-    let now = Instant::now();
-    let solution = p2(&input)?;
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day21 {
+    type Input<'a> = Input<'a>;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input)?.into())
+    }
 }
+
+pub const DAY: Day = Day::new(21, &[Part::<Day21>::part1(), Part::<Day21>::part2()]);
@@ -1,35 +1,12 @@
-use std::{collections::VecDeque, error::Error, ops::Add, time::Instant};
+use std::error::Error;
 
+use aoc_common::{Answer, Day, Part, Point2, Solution, StateSpace, bfs};
 use itertools::Itertools;
-use rustc_hash::{FxHashMap, FxHashSet};
-
-const INPUT: &str = include_str!("inputs/day22.txt");
+use rustc_hash::FxHashMap;
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 type Input<'a> = FxHashMap<Point, Node>;
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Point {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add for Point {
-    type Output = Point;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
+type Point = Point2<isize>;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 struct Node {
@@ -87,65 +64,58 @@ fn p2(input: &Input) -> Result<usize> {
     let wall_size = input.get(empty).unwrap().avail;
 
     // Figure out how far we need to go to move the empty node.
-    let dist_to_start_neighbor = bfs(input, empty, bfs_goal, wall_size);
+    let cluster = Cluster {
+        grid: input,
+        wall_size,
+        goal: bfs_goal,
+    };
+    let dist_to_start_neighbor = bfs(&cluster, *empty).map_or(0, |found| found.cost);
 
     // Then it's just that distance plus the number of rotations you have to do to get the node
     // moved over.
     Ok(dist_to_start_neighbor + (max_x as usize - 1) * 5 + 1)
 }
 
-fn bfs(grid: &Input, start: &Point, end: Point, wall_size: usize) -> usize {
-    let mut frontier = VecDeque::new();
-    frontier.push_back((*start, 0));
-
-    let mut visited = FxHashSet::default();
-    visited.insert(*start);
-
-    let deltas = [
-        Point::new(0, 1),
-        Point::new(0, -1),
-        Point::new(1, 0),
-        Point::new(-1, 0),
-    ];
-
-    while let Some((cur, dist)) = frontier.pop_front() {
-        if cur == end {
-            return dist;
-        }
-
-        for delta in deltas {
-            // Check to see if we have the next point on the grid.
-            let next = cur + delta;
-            let node = match grid.get(&next) {
-                Some(n) => n,
-                None => continue,
-            };
-
-            // Make sure we can move (not a wall)
-            if node.used > wall_size {
-                continue;
-            }
-
-            // Only add to frontier if we haven't been visited.
-            if visited.insert(next) {
-                frontier.push_back((next, dist + 1));
-            }
-        }
+// Moving the empty node around the grid. Nodes bigger than the walls can't be moved.
+struct Cluster<'a> {
+    grid: &'a Input<'a>,
+    wall_size: usize,
+    goal: Point,
+}
+
+impl StateSpace for Cluster<'_> {
+    type State = Point;
+
+    fn successors(&self, cur: &Point) -> impl IntoIterator<Item = Point> {
+        cur.neighbors4().filter(|next| {
+            // Check to see if we have the next point on the grid and we can move (not a wall).
+            self.grid
+                .get(next)
+                .is_some_and(|node| node.used <= self.wall_size)
+        })
     }
 
-    0
+    fn is_goal(&self, cur: &Point) -> bool {
+        *cur == self.goal
+    }
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT)?;
-    let solution = p1(&input)?;
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day22;
 
-    let now = Instant::now();
-    let solution = p2(&input)?;
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Day22 {
+    type Input<'a> = Input<'a>;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input)?.into())
+    }
 }
 
+pub const DAY: Day = Day::new(22, &[Part::<Day22>::part1(), Part::<Day22>::part2()]);
@@ -1,4 +1,6 @@
-use std::{error::Error, time::Instant};
+use std::error::Error;
+
+use aoc_common::{Answer, Day, Part, Solution};
 
 #[derive(Debug, Copy, Clone)]
 enum Value {
@@ -55,7 +57,6 @@ impl Instruction {
     }
 }
 
-const INPUT: &str = include_str!("inputs/day23.txt");
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 type Input<'a> = Vec<Instruction>;
 
@@ -167,16 +168,23 @@ fn p2(_input: &Input) -> Result<isize> {
     Ok(decode_input(12))
 }
 
-fn main() -> Result<()> {
-    let now = Instant::now();
-    let input = parse_input(INPUT)?;
-    let solution = p1(&input)?;
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Input<'a>;
 
-    let now = Instant::now();
-    let solution = p2(&input)?;
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input)?.into())
+    }
 }
 
+pub const DAY: Day = Day::new(23, &[Part::<Day23>::part1(), Part::<Day23>::part2()]);
+
@@ -0,0 +1,84 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Day, Grid, HeldKarp, Part, Point2, Solution, StateSpace, bfs};
+
+type Point = Point2<usize>;
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+type Input<'a> = (Point, Vec<Point>, Grid<u8>);
+
+fn parse_input(input: &'_ str) -> Result<Input<'_>> {
+    let mut grid = Grid::from_bytes(input.trim())?.map(|&b| b);
+
+    // The numbered locations are open spaces we want to visit.
+    let locations: Vec<Point> = grid
+        .points()
+        .filter(|p| grid[*p].is_ascii_digit())
+        .collect();
+    let zero = grid.find(&b'0').ok_or("no location 0")?;
+    for p in &locations {
+        grid[*p] = b'.';
+    }
+    Ok((zero, locations, grid))
+}
+
+// Walking the open spaces of the ducts to one of the locations.
+struct Ducts<'a> {
+    grid: &'a Grid<u8>,
+    to: Point,
+}
+
+impl StateSpace for Ducts<'_> {
+    type State = Point;
+
+    fn successors(&self, p: &Point) -> impl IntoIterator<Item = Point> {
+        self.grid.neighbors4(*p).filter(|p| self.grid[*p] == b'.')
+    }
+
+    fn is_goal(&self, p: &Point) -> bool {
+        *p == self.to
+    }
+}
+
+fn distance(grid: &Grid<u8>, from: Point, to: Point) -> usize {
+    bfs(&Ducts { grid, to }, from).unwrap().cost
+}
+
+// The distances between each of the locations along with where location 0 is in the matrix.
+fn distances((zero, locations, grid): &Input) -> (usize, Grid<usize>) {
+    let start = locations.iter().position(|p| p == zero).unwrap();
+    let distances = Grid::from_fn(locations.len(), locations.len(), |p| {
+        distance(grid, locations[p.y], locations[p.x])
+    });
+    (start, distances)
+}
+
+fn p1(input: &Input) -> Result<usize> {
+    let (start, distances) = distances(input);
+    Ok(HeldKarp::new(&distances).start(start).solve().cost)
+}
+
+fn p2(input: &Input) -> Result<usize> {
+    // We have to get back to 0 too.
+    let (start, distances) = distances(input);
+    Ok(HeldKarp::new(&distances).start(start).cycle().solve().cost)
+}
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new(24, &[Part::<Day24>::part1(), Part::<Day24>::part2()]);
@@ -0,0 +1,164 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Day, Part, Solution, find_cycle};
+
+#[derive(Debug)]
+enum Value {
+    Literal(isize),
+    Register(usize),
+}
+
+impl Value {
+    fn parse(input: &str) -> Self {
+        match input.parse::<isize>() {
+            Ok(i) => Self::Literal(i),
+            _ => Self::Register(convert_register(input)),
+        }
+    }
+
+    fn evaluate(&self, registers: &[isize]) -> isize {
+        match self {
+            Self::Literal(i) => *i,
+            Self::Register(r) => registers[*r],
+        }
+    }
+}
+
+fn convert_register(input: &str) -> usize {
+    match input {
+        "a" => 0,
+        "b" => 1,
+        "c" => 2,
+        "d" => 3,
+        _ => panic!("invalid value: {}", input),
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Cpy(Value, usize),
+    Inc(usize),
+    Dec(usize),
+    Out(Value),
+    Jnz(Value, isize),
+}
+
+impl Instruction {
+    fn parse(input: &str) -> Self {
+        let parts = input.split_whitespace().collect::<Vec<&str>>();
+        match parts[..] {
+            ["cpy", x, y] => Instruction::Cpy(Value::parse(x), convert_register(y)),
+            ["inc", x] => Instruction::Inc(convert_register(x)),
+            ["dec", x] => Instruction::Dec(convert_register(x)),
+            ["out", x] => Instruction::Out(Value::parse(x)),
+            ["jnz", x, y] => Instruction::Jnz(Value::parse(x), y.parse().unwrap()),
+            _ => panic!("invalid instruction: {}", input),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+type Input<'a> = Vec<Instruction>;
+
+fn parse_input(input: &'_ str) -> Result<Input<'_>> {
+    Ok(input.lines().map(Instruction::parse).collect())
+}
+
+// Where the program is and what it last sent out, or None once it's stopped or broken the
+// pattern.
+type State = Option<(isize, [isize; 4], isize)>;
+
+// Run until the next thing is sent out.
+fn next_out(input: &Input, state: &State) -> State {
+    let (mut pc, mut registers, prev) = (*state)?;
+    while pc >= 0 && pc < input.len() as isize {
+        match &input[pc as usize] {
+            Instruction::Cpy(v, r) => registers[*r] = v.evaluate(&registers),
+            Instruction::Inc(r) => registers[*r] += 1,
+            Instruction::Dec(r) => registers[*r] -= 1,
+            Instruction::Out(v) => {
+                let out = v.evaluate(&registers);
+                return (out == 1 - prev).then_some((pc + 1, registers, out));
+            }
+            Instruction::Jnz(v, i) => {
+                if v.evaluate(&registers) != 0 {
+                    pc += i;
+                    continue;
+                }
+            }
+        }
+        pc += 1;
+    }
+    None
+}
+
+// The programs we've seen come back round within a few dozen outputs, but one that keeps
+// alternating while a register grows never would, and every state gets kept until it does. After
+// this many outputs it's taken as alternating for good, which is all the check used to be.
+const MAX_OUTPUTS: usize = 100;
+
+fn sim(input: &Input, a: isize) -> bool {
+    // Once the program is back somewhere it's been, it'll send the same things out forever, so it
+    // only has to alternate up to there. Pretending a 1 went out first means it has to start at 0.
+    // Stopping at the cap repeats the last state, which ends the search too.
+    let start = (0, Some((0, [a, 0, 0, 0], 1)));
+    let step = |&(outputs, state): &(usize, State)| match outputs < MAX_OUTPUTS {
+        true => (outputs + 1, next_out(input, &state)),
+        false => (outputs, state),
+    };
+    let (_, states) = find_cycle(start, step, |&(_, state)| state);
+    states.iter().all(|(_, state)| state.is_some())
+}
+
+fn p1(input: &Input) -> Result<usize> {
+    Ok((0..)
+        .map(|a| (a, sim(input, a)))
+        .find(|(_, found)| *found)
+        .map(|(a, _)| a as usize)
+        .unwrap())
+}
+
+// The program adds c * b to a and sends out its bits, lowest first. The two numbers are the first
+// literals copied into c and b.
+fn p1_decode_input(input: &Input) -> Result<usize> {
+    let first = |register| {
+        input.iter().find_map(|instruction| match instruction {
+            Instruction::Cpy(Value::Literal(n), r) if *r == register => Some(*n as usize),
+            _ => None,
+        })
+    };
+    let (Some(c), Some(b)) = (first(2), first(1)) else {
+        return Err("not the program this was worked out from".into());
+    };
+
+    Ok((0..)
+        .find(|&a| {
+            let n = a + c * b;
+            let x = n ^ (n >> 1);
+            (x & (x + 1)) == 0 && 1 & n == 0
+        })
+        .unwrap())
+}
+
+struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input)?.into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    25,
+    &[
+        Part::<Day25>::part1(),
+        Part::<Day25>::variant(1, "p1_decode", |input| Ok(p1_decode_input(input)?.into())),
+    ],
+);
+
@@ -0,0 +1,53 @@
+use aoc_common::{Answer, Day, Part, Result, Solution};
+
+type Input<'a> = Vec<&'a str>;
+
+fn parse_input(input: &'_ str) -> Result<Input<'_>> {
+    // TODO did you trim today?
+    Ok(input.trim().lines().collect())
+}
+
+fn p1(input: &Input) -> usize {
+    input.len()
+}
+
+fn p2(input: &Input) -> usize {
+    input.len()
+}
+
+struct [STRUCT];
+
+impl Solution for [STRUCT] {
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new([DAY], &[Part::<[STRUCT]>::part1(), Part::<[STRUCT]>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/[NAME]-sample.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(&parse_input(INPUT).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(&parse_input(INPUT).unwrap()), 0);
+    }
+}
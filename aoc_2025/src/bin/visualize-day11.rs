@@ -9,9 +9,8 @@ fn to_rgb(color: catppuccin::Color) -> RGBColor {
     RGBColor(color.rgb.r, color.rgb.g, color.rgb.b)
 }
 
-const INPUT: &str = include_str!("inputs/day11.txt");
-const INPUT_SAMPLE: &str = include_str!("inputs/day11-sample.txt");
-const INPUT_SAMPLE2: &str = include_str!("inputs/day11-sample2.txt");
+const INPUT_SAMPLE: &str = include_str!("../../inputs/day11-sample.txt");
+const INPUT_SAMPLE2: &str = include_str!("../../inputs/day11-sample2.txt");
 
 fn parse(input: &str) -> FxHashMap<&str, Vec<&str>> {
     input
@@ -130,7 +129,7 @@ fn visualize_graph(
                 if let Some(&(x2, y2)) = positions.get(neighbor) {
                     chart.draw_series(std::iter::once(PathElement::new(
                         vec![(x1, y1), (x2, y2)],
-                        &edge_color,
+                        edge_color,
                     )))?;
                 }
             }
@@ -180,20 +179,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let (input, output_file, start_node) = if args.len() > 1 {
         match args[1].as_str() {
-            "--sample" => (INPUT_SAMPLE, "aoc_2025/day11_graph_sample.png", "you"),
-            "--sample2" => (INPUT_SAMPLE2, "aoc_2025/day11_graph_sample2.png", "svr"),
+            "--sample" => (
+                INPUT_SAMPLE.to_string(),
+                "aoc_2025/day11_graph_sample.png",
+                "you",
+            ),
+            "--sample2" => (
+                INPUT_SAMPLE2.to_string(),
+                "aoc_2025/day11_graph_sample2.png",
+                "svr",
+            ),
             _ => {
                 eprintln!("Usage: visualize-day11 [--sample|--sample2]");
                 return Ok(());
             }
         }
     } else {
-        (INPUT, "aoc_2025/day11_graph.png", "svr")
+        (
+            aoc_common::load_input(2025, 11, None)?,
+            "aoc_2025/day11_graph.png",
+            "svr",
+        )
     };
 
     let special_nodes = vec!["you", "fft", "dac"];
 
-    visualize_graph(input, output_file, start_node, "out", &special_nodes)?;
+    visualize_graph(&input, output_file, start_node, "out", &special_nodes)?;
 
     Ok(())
 }
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day01.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn parse(input: &'_ str) -> impl Iterator<Item = (char, isize)> {
     input.lines().map(|l| {
@@ -55,16 +53,26 @@ fn p2(input: &str) -> isize {
         .sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(1, &[Part::<Day01>::part1(), Part::<Day01>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
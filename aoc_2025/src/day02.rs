@@ -0,0 +1,154 @@
+use aoc_common::{Answer, Day, IntervalSet, Part, Result, Solution};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use std::ops::RangeInclusive;
+
+fn parse(input: &str) -> impl Iterator<Item = RangeInclusive<usize>> {
+    // Convert the input into a list of ranges.
+    input.trim().split(',').map(|l| {
+        l.split_once('-')
+            .map(|(l, r)| l.parse().unwrap()..=r.parse().unwrap())
+            .unwrap()
+    })
+}
+
+// Every number up to max that's a block of digits repeated some number of times, where
+// `allowed` says which numbers of repeats count.
+fn repeats(max: usize, allowed: impl Fn(u32) -> bool) -> FxHashSet<usize> {
+    let mut found = FxHashSet::default();
+    if max == 0 {
+        return found;
+    }
+    for len in 2..=max.ilog10() + 1 {
+        for block in (1..len).filter(|&b| len.is_multiple_of(b) && allowed(len / b)) {
+            // Multiplying by 1, 101, 10101 and so on repeats a block.
+            let spread = (0..len / block).fold(0usize, |acc, _| acc * 10usize.pow(block) + 1);
+            for prefix in 10usize.pow(block - 1)..10usize.pow(block) {
+                match prefix.checked_mul(spread) {
+                    Some(n) if n <= max => found.insert(n),
+                    _ => break,
+                };
+            }
+        }
+    }
+    found
+}
+
+// Rather than checking every ID in the ranges, make the invalid IDs and look them up. There are
+// far fewer of them.
+fn sum_invalid(input: &str, allowed: impl Fn(u32) -> bool) -> usize {
+    let ids: IntervalSet<usize> = parse(input).collect();
+    let max = ids.iter().last().map_or(0, |r| *r.end());
+    repeats(max, allowed)
+        .into_iter()
+        .filter(|&id| ids.contains(id))
+        .sum()
+}
+
+fn p1(input: &str) -> usize {
+    sum_invalid(input, |repeats| repeats == 2)
+}
+
+fn p2(input: &str) -> usize {
+    sum_invalid(input, |_| true)
+}
+
+fn p1_enumerate(input: &str) -> usize {
+    parse(input)
+        .flatten()
+        .filter(|&v| {
+            let digits = v.ilog10() + 1;
+            if !digits.is_multiple_of(2) {
+                return false;
+            }
+            let denom = 10usize.pow(digits / 2);
+            v / denom == v % denom
+        })
+        .sum()
+}
+
+fn p2_enumerate(input: &str) -> usize {
+    parse(input)
+        .par_bridge()
+        .flatten()
+        .filter(|&v| {
+            // Try all grouping lengths up to half.
+            let digits = (v.ilog10() + 1) as usize;
+            for d in 1..=digits / 2 {
+                // Must equally divide.
+                if !digits.is_multiple_of(d) {
+                    continue;
+                }
+
+                // The prefix we are looking at for this iteration.
+                let prefix = v / 10_usize.pow((digits - d) as u32);
+
+                // Repeat it for the rest of the digits
+                let repeated =
+                    (1..digits / d).fold(prefix, |acc, _| acc * 10_usize.pow(d as u32) + prefix);
+
+                // If the repeated value is the same as the original, it's a match.
+                if repeated == v {
+                    return true;
+                }
+            }
+            false
+        })
+        .sum()
+}
+
+struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    2,
+    &[
+        Part::<Day02>::part1(),
+        Part::<Day02>::part2(),
+        Part::<Day02>::variant(1, "p1_enumerate", |input| Ok(p1_enumerate(input).into())),
+        Part::<Day02>::variant(2, "p2_enumerate", |input| Ok(p2_enumerate(input).into())),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 1227775554);
+    }
+
+    #[test]
+    fn test_p2_int() {
+        assert_eq!(p2(INPUT), 4174379265);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(p1("0-0"), 0);
+        assert_eq!(p2("0-0"), 0);
+    }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(INPUT).unwrap();
+    }
+}
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-const INPUT: &str = include_str!("inputs/day03.txt");
+use aoc_common::{Answer, Day, Part, Result, Solution};
 
 fn parse(input: &str) -> impl Iterator<Item = Vec<usize>> {
     // Turn each line into a list of digits.
@@ -72,16 +70,26 @@ fn p2(input: &str) -> usize {
     parse(input).map(|bank| greedy(&bank, 12, 0)).sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(3, &[Part::<Day03>::part1(), Part::<Day03>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
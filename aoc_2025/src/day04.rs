@@ -0,0 +1,89 @@
+use aoc_common::{Answer, Day, Grid, Part, Point2, Result, Solution};
+use rustc_hash::FxHashSet;
+
+type Point = Point2<usize>;
+
+// Whether there is a roll of paper at p that can be reached.
+fn accessible(grid: &Grid<bool>, p: Point) -> bool {
+    grid[p] && grid.neighbors8(p).filter(|n| grid[*n]).count() < 4
+}
+
+fn p1(grid: &Grid<bool>) -> usize {
+    // grid marks the points where there are rolls of paper.
+    // Get a count of all points that have fewer than four neighbors.
+    grid.points().filter(|p| accessible(grid, *p)).count()
+}
+
+fn p2(grid: &Grid<bool>) -> usize {
+    let mut grid = grid.clone();
+    let mut total = 0;
+
+    // Start with the initial set of points to remove.
+    let mut remove = grid
+        .points()
+        .filter(|p| accessible(&grid, *p))
+        .collect::<FxHashSet<_>>();
+
+    // Loop as long as we are removing points.
+    while !remove.is_empty() {
+        total += remove.len();
+
+        // The only potential candidates for removal are those around our remove set.
+        let candidates = remove
+            .iter()
+            .flat_map(|p| grid.neighbors8(*p))
+            .filter(|p| grid[*p] && !remove.contains(p))
+            .collect::<FxHashSet<_>>();
+
+        // Remove them from the main grid.
+        for p in &remove {
+            grid[*p] = false;
+        }
+
+        // From the candidates, find which ones to remove in the next round.
+        remove = candidates
+            .into_iter()
+            .filter(|p| accessible(&grid, *p))
+            .collect();
+    }
+
+    total
+}
+
+struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // Turn the grid into a grid of where the barrels are.
+        Ok(Grid::from_bytes(input)?.map(|&b| b == b'@'))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(4, &[Part::<Day04>::part1(), Part::<Day04>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(&Day04::parse(INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(&Day04::parse(INPUT).unwrap()), 43);
+    }
+}
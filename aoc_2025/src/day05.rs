@@ -0,0 +1,66 @@
+use aoc_common::{Answer, Day, IntervalSet, Part, Result, Solution};
+
+fn parse(input: &str) -> (IntervalSet<usize>, impl Iterator<Item = usize>) {
+    let (ranges, ingredients) = input.split_once("\n\n").unwrap();
+
+    // Collect our ranges, merging them as we go.
+    let fresh = ranges
+        .lines()
+        .map(|l| l.split_once('-').unwrap())
+        .map(|(l, r)| l.parse().unwrap()..=r.parse().unwrap())
+        .collect();
+
+    // Return our ranges and an iterator over our items.
+    (fresh, ingredients.lines().map(|l| l.parse().unwrap()))
+}
+
+fn p1(input: &str) -> usize {
+    let (fresh, ingredients) = parse(input);
+    // Count all ingredients that are within any range.
+    ingredients
+        .filter(|&ingredient| fresh.contains(ingredient))
+        .count()
+}
+
+fn p2(input: &str) -> usize {
+    // The ranges are already merged, so we just need how much they cover.
+    let (fresh, _) = parse(input);
+    fresh.len() as usize
+}
+
+struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(5, &[Part::<Day05>::part1(), Part::<Day05>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 3);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(INPUT), 14);
+    }
+}
@@ -1,15 +1,9 @@
-use std::time::Instant;
+use aoc_common::{Answer, ByteGrid, Day, Grid, Part, Result, Solution};
 
-const INPUT: &[u8] = include_bytes!("inputs/day06.txt");
-
-fn p1(input: &[u8]) -> usize {
-    // Determine how long each line will be.
-    let stride = input.iter().position(|&b| b == b'\n').unwrap() + 1;
-
-    // Find out how many lines there are and where our data and ops are.
-    let lines = input.len().div_ceil(stride);
-    let data = lines - 1;
-    let ops = &input[data * stride..];
+fn p1(grid: &ByteGrid) -> usize {
+    // The last line has the ops and the rest are data.
+    let data = grid.height() - 1;
+    let ops = grid.row(data);
 
     // Track our total for the entire solution here.
     let mut total = 0;
@@ -25,7 +19,7 @@ fn p1(input: &[u8]) -> usize {
         let mut block = if b == b'*' { 1 } else { 0 };
         for row in 0..data {
             // Calculate the value at this row.
-            let val = input[(row * stride) + cur..(row * stride) + end]
+            let val = grid.row(row)[cur..end]
                 .iter()
                 .filter(|c| c.is_ascii_digit())
                 .fold(0, |acc, c| acc * 10 + (c - b'0') as usize);
@@ -45,14 +39,10 @@ fn p1(input: &[u8]) -> usize {
     total
 }
 
-fn p2(input: &[u8]) -> usize {
-    // Determine how long each line will be.
-    let stride = input.iter().position(|&b| b == b'\n').unwrap() + 1;
-
-    // Find out how many lines there are and where our data and ops are.
-    let lines = input.len().div_ceil(stride);
-    let data = lines - 1;
-    let ops = &input[data * stride..];
+fn p2(grid: &ByteGrid) -> usize {
+    // The last line has the ops and the rest are data.
+    let data = grid.height() - 1;
+    let ops = grid.row(data);
 
     // Track our total for the entire solution here.
     let mut total = 0;
@@ -74,7 +64,7 @@ fn p2(input: &[u8]) -> usize {
         for col in cur..end {
             let mut val = 0;
             for row in 0..data {
-                let c = input[row * stride + col];
+                let c = grid[(row, col)];
                 if c.is_ascii_digit() {
                     val = val * 10 + (c - b'0') as usize;
                 }
@@ -94,29 +84,38 @@ fn p2(input: &[u8]) -> usize {
     total
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1_fast {:?} {}", now.elapsed(), solution);
+struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = ByteGrid<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::from_bytes(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2_fast {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(6, &[Part::<Day06>::part1(), Part::<Day06>::part2()]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &[u8] =
-        "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n".as_bytes();
+    const INPUT: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
 
     #[test]
     fn test_p1() {
-        assert_eq!(p1(INPUT), 4277556);
+        assert_eq!(p1(&Grid::from_bytes(INPUT).unwrap()), 4277556);
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(p2(INPUT), 3263827);
+        assert_eq!(p2(&Grid::from_bytes(INPUT).unwrap()), 3263827);
     }
 }
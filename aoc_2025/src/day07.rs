@@ -0,0 +1,186 @@
+use aoc_common::{Answer, BitSet, ByteGrid, Day, Grid, Part, Result, Solution};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+fn p1_original(grid: &ByteGrid) -> usize {
+    // Track our beams as a set.
+    let mut beams = FxHashSet::default();
+    beams.insert(grid.find(&b'S').unwrap().x);
+
+    let mut splits = 0;
+    for row in grid.rows().step_by(2) {
+        // For each row, find all the splitters.
+        for (column, _) in row.iter().enumerate().filter(|(_, c)| **c == b'^') {
+            // If a beam is going to hit the splitter, update out count, add the splits and remove
+            // ourself.
+            if beams.contains(&column) {
+                splits += 1;
+                beams.insert(column + 1);
+                beams.insert(column - 1);
+                beams.remove(&column);
+            }
+        }
+    }
+
+    splits
+}
+
+fn p1(grid: &ByteGrid) -> usize {
+    // Track our beams as a bitset.
+    let mut beams = BitSet::new(grid.width());
+    beams.set(grid.find(&b'S').unwrap().x);
+
+    // Go through each of the lines and find the beams that overlap with splitters.
+    grid.rows()
+        .step_by(2)
+        .map(|row| {
+            let mut splits = 0;
+            beams = row.iter().enumerate().filter(|(_, v)| **v == b'^').fold(
+                std::mem::take(&mut beams),
+                |mut acc, (i, _)| {
+                    // If we found a beam and splitter that overlap, increment our splits and
+                    // update our set.
+                    if acc.test(i) {
+                        splits += 1;
+                        acc.unset(i);
+                        acc.set(i + 1);
+                        acc.set(i - 1);
+                    }
+                    acc
+                },
+            );
+            splits
+        })
+        .sum()
+}
+
+fn p2_original(grid: &ByteGrid) -> usize {
+    // Track our timelines now in a map as they'll increase with each split.
+    let mut beams = FxHashMap::default();
+    beams.insert(grid.find(&b'S').unwrap().x, 1);
+
+    for row in grid.rows().skip(1) {
+        row.iter()
+            .enumerate()
+            .filter(|(_, c)| **c == b'^')
+            .for_each(|(i, _)| {
+                // For each splitter that's a part of a beam, we want to increment the timelines for each of the neighbors.
+                if let Some(count) = beams.remove(&i) {
+                    *beams.entry(i + 1).or_insert(0) += count;
+                    *beams.entry(i - 1).or_insert(0) += count;
+                }
+            });
+    }
+
+    // Now it's just the sum of all the timelines in the map.
+    beams.values().sum()
+}
+
+fn p2(grid: &ByteGrid) -> usize {
+    // For p2, we want to track the timelines. We'll only ever get one set of timelines per column, so we can use a vec.
+    let mut timelines = vec![0usize; grid.width()];
+    timelines[grid.find(&b'S').unwrap().x] = 1;
+
+    // We can step by two because the input has empty alternating rows.
+    for row in grid.rows().step_by(2) {
+        // Update our timelines when we encounter a splitter.
+        let mut next_beams = timelines.clone();
+        for (i, _) in row
+            .iter()
+            .enumerate()
+            .filter(|(i, v)| **v == b'^' && timelines[*i] != 0)
+        {
+            let count = timelines[i];
+            next_beams[i] = 0;
+            next_beams[i + 1] += count;
+            next_beams[i - 1] += count;
+        }
+        timelines = next_beams;
+    }
+
+    timelines.iter().sum()
+}
+
+struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = ByteGrid<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::from_bytes(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    7,
+    &[
+        Part::<Day07>::part1(),
+        Part::<Day07>::part2(),
+        Part::<Day07>::variant(1, "p1_original", |input| Ok(p1_original(input).into())),
+        Part::<Day07>::variant(2, "p2_original", |input| Ok(p2_original(input).into())),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............\n";
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1_original(&Grid::from_bytes(INPUT).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2_original(&Grid::from_bytes(INPUT).unwrap()), 40);
+    }
+
+    #[test]
+    fn test_p1_fast() {
+        assert_eq!(p1(&Grid::from_bytes(INPUT).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_p2_fast() {
+        assert_eq!(p2(&Grid::from_bytes(INPUT).unwrap()), 40);
+    }
+
+    #[test]
+    fn test_wide() {
+        // Beams past column 128 used to land in the wrong word of the mask.
+        let row = |cols: &[(usize, char)]| {
+            let mut row = vec!['.'; 200];
+            for &(c, v) in cols {
+                row[c] = v;
+            }
+            row.into_iter().collect::<String>() + "\n"
+        };
+        let input = [
+            row(&[(128, 'S')]),
+            row(&[]),
+            row(&[(128, '^')]),
+            row(&[]),
+            row(&[(127, '^'), (129, '^')]),
+            row(&[]),
+        ]
+        .concat();
+        let grid = Grid::from_bytes(&input).unwrap();
+        assert_eq!(p1(&grid), 3);
+        assert_eq!(p2(&grid), 4);
+        DAY.check_variants(&input).unwrap();
+    }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(INPUT).unwrap();
+    }
+}
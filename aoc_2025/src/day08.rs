@@ -0,0 +1,115 @@
+use aoc_common::{Answer, Day, DisjointSet, Part, Point3, Result, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+type Point = Point3<f64>;
+
+fn parse(input: &str) -> impl Iterator<Item = Point> {
+    input.lines().map(|l| l.parse().unwrap())
+}
+
+// BUG(FIXED): the test case sets a limit of 10, which was giving me the wrong answer.
+fn p1(input: &str, limit: usize) -> usize {
+    let points = parse(input).collect::<Vec<_>>();
+
+    // Get the distances among all points. The real distance includes a sqrt() but we are only
+    // interested in the order, so the square is enough.
+    let mut dists = points
+        .iter()
+        .enumerate()
+        .flat_map(|(i1, p1)| {
+            points[i1 + 1..]
+                .iter()
+                .enumerate()
+                // BUG(FIXED): i2 is from i1+1
+                .map(move |(i2, p2)| ((i1, i1 + 1 + i2), p1.distance_squared(p2)))
+        })
+        .collect::<Vec<((usize, usize), f64)>>();
+
+    // We don't have to completely sort, just make sure 0..limit are the smallest.
+    // "kth element" and then truncate (very minor optimization)
+    // https://en.wikipedia.org/wiki/Quickselect
+    // [ipnsort]: https://github.com/Voultapher/sort-research-rs/tree/main/ipnsort
+    // [total order]: https://en.wikipedia.org/wiki/Total_order
+    dists.select_nth_unstable_by(limit, |(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap());
+    dists.truncate(limit);
+
+    // Add unions to our disjoint set.
+    let mut ds = DisjointSet::new(points.len());
+    for ((i1, i2), _) in dists {
+        ds.union(i1, i2);
+    }
+
+    // We know the sizes of each group, so we can simply sort them and take the product of the largest 3.
+    ds.sizes()
+        .sorted_unstable_by(|a, b| b.cmp(a)) // BUG(FIXED): largest
+        .take(3)
+        .product()
+}
+
+// p2 is largely the same as p1, we just track when all of the values have been sorted.
+fn p2(input: &str) -> usize {
+    let points = parse(input).collect::<Vec<_>>();
+
+    let mut dists = points
+        .iter()
+        .enumerate()
+        .flat_map(|(i1, p1)| {
+            points[i1 + 1..]
+                .iter()
+                .enumerate()
+                .map(move |(i2, p2)| ((i1, i1 + 1 + i2), p1.distance_squared(p2)))
+        })
+        .collect::<Vec<((usize, usize), f64)>>();
+
+    // Sorting in parallel actually helps here.
+    dists.par_sort_unstable_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap());
+
+    let mut ds = DisjointSet::new(points.len());
+    for ((i1, i2), _) in dists {
+        // Go until we find the last merge.
+        ds.union(i1, i2);
+        if ds.count() == 1 {
+            return (points[i1].x * points[i2].x) as usize;
+        }
+    }
+
+    unreachable!()
+}
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input, 1000).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(8, &[Part::<Day08>::part1(), Part::<Day08>::part2()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/day08-example.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT, 10), 40);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(INPUT), 25272);
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_common::{Answer, CompressedPlane, Day, Part, Point2, Region, Result, Solution};
+use itertools::Itertools;
+// TODO: Cool trick is that you can sort of infer that the largest box will be along the horizontal lines, so you can just sort of check them to see which are largest. https://www.reddit.com/r/adventofcode/comments/1phywvn/comment/nt2nnxw/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
+
+// Tiles are given as "x,y" which is column then row.
+type Tile = Point2<isize>;
+
+fn area(t1: &Tile, t2: &Tile) -> usize {
+    (t1.y.abs_diff(t2.y) + 1) * (t1.x.abs_diff(t2.x) + 1)
+}
+
+fn parse(input: &str) -> impl Iterator<Item = Tile> {
+    input.trim().lines().map(|l| l.parse().unwrap())
+}
+
+fn p1(input: &str) -> usize {
+    // For each Tile find it's area with all other tiles and then return the max.
+    let input = parse(input).collect::<Vec<_>>();
+    input
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t1)| input[i + 1..].iter().map(move |t2| area(t1, t2)))
+        .max()
+        .unwrap()
+}
+
+// very simple valid check. All the compressed cells inside the rectangle need to not be outside.
+fn valid(plane: &CompressedPlane, t1: &Tile, t2: &Tile) -> bool {
+    let (s1, s2) = (plane.slot(*t1).unwrap(), plane.slot(*t2).unwrap());
+    (s1.y.min(s2.y)..=s1.y.max(s2.y))
+        .cartesian_product(s1.x.min(s2.x)..=s1.x.max(s2.x))
+        .all(|(r, c)| plane.regions()[(r, c)] != Region::Outside)
+}
+
+fn p2_old(input: &str) -> usize {
+    // Create our compressed plane from the tiles. It marks the boundary and flood fills the
+    // outside for us.
+    let tiles = parse(input).collect::<Vec<_>>();
+    let plane = CompressedPlane::from_polygon(&tiles).unwrap();
+
+    // Now we can simply look through the rectangles formed and find the largest one in the *original* grid.
+    tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t1)| tiles[i + 1..].iter().map(move |t2| (t1, t2)))
+        .filter(|(t1, t2)| valid(&plane, t1, t2))
+        .map(|(t1, t2)| area(t1, t2))
+        .max()
+        .unwrap()
+}
+
+fn p2(input: &str) -> usize {
+    // Now we can do the same thing, but instead use the prefix sum of the covered area in the
+    // plane to check each rectangle in constant time.
+    let tiles = parse(input).collect::<Vec<_>>();
+    let plane = CompressedPlane::from_polygon(&tiles).unwrap();
+
+    tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t1)| tiles[i + 1..].iter().map(move |t2| (t1, t2)))
+        .filter(|(t1, t2)| plane.contains_rect(**t1, **t2))
+        .map(|(t1, t2)| area(t1, t2))
+        .max()
+        .unwrap()
+}
+
+struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    9,
+    &[
+        Part::<Day09>::part1(),
+        Part::<Day09>::part2(),
+        Part::<Day09>::variant(2, "p2_old", |input| Ok(p2_old(input).into())),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/day09-sample.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 50);
+    }
+
+    #[test]
+    fn test_p2_pfx() {
+        assert_eq!(p2(INPUT), 24);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2_old(INPUT), 24);
+    }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(INPUT).unwrap();
+    }
+}
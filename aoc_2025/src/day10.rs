@@ -0,0 +1,278 @@
+use aoc_common::{
+    Answer, Day, IntegerProgram, LinearSystem, Matrix, Part, Result, Solution, StateSpace, bfs,
+};
+use rayon::iter::{ParallelBridge, ParallelIterator};
+#[cfg(feature = "z3")]
+use z3::{Optimize, ast::Int};
+
+fn parse(input: &str) -> impl Iterator<Item = Machine> {
+    input.trim().lines().map(Machine::from)
+}
+
+#[derive(Debug)]
+struct Machine {
+    lights: usize,
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<usize>,
+}
+
+impl From<&str> for Machine {
+    fn from(value: &str) -> Self {
+        let mut parts = value.split_whitespace();
+        let lights = parts
+            .next()
+            .map(|l| {
+                // We rev here to make calculating below easier.
+                l.trim_matches(['[', ']'])
+                    .chars()
+                    .rev()
+                    .fold(0, |acc, c| (acc << 1) | if c == '#' { 1 } else { 0 })
+            })
+            .unwrap();
+
+        let mut parts: Vec<&str> = parts.collect();
+        let joltages = parts
+            .pop()
+            .unwrap()
+            .trim_matches(['{', '}'])
+            .split(',')
+            .map(|v| v.parse().unwrap())
+            .collect();
+
+        let mut buttons: Vec<Vec<usize>> = parts
+            .iter()
+            .map(|b| {
+                b.trim_matches(['(', ')'])
+                    .split(',')
+                    .map(|v| v.parse().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        // Sorting seems to help here. Not sure why, was just trying stuff.
+        buttons.sort_by_key(|b| std::cmp::Reverse(b.len()));
+
+        Self {
+            lights,
+            buttons,
+            joltages,
+        }
+    }
+}
+
+// The lights are a bitmask, starting all off. Each button press toggles some of them.
+impl StateSpace for Machine {
+    type State = usize;
+
+    fn successors(&self, lights: &usize) -> impl IntoIterator<Item = usize> {
+        self.buttons
+            .iter()
+            .map(move |button| button.iter().fold(*lights, |acc, n| acc ^ (1 << n)))
+    }
+
+    fn is_goal(&self, lights: &usize) -> bool {
+        *lights == self.lights
+    }
+}
+
+fn p1(input: &str) -> usize {
+    parse(input)
+        .map(|machine| bfs(&machine, 0).unwrap().cost)
+        .sum()
+}
+
+// Each button is a column and each joltage a row, so the presses x solve buttons * x = joltages.
+fn equations(machine: &Machine) -> (Matrix, Vec<i128>) {
+    let buttons = Matrix::from_fn(machine.joltages.len(), machine.buttons.len(), |r, c| {
+        machine.buttons[c].contains(&r) as i128
+    });
+    let joltages = machine
+        .joltages
+        .iter()
+        .map(|&j| j as i128)
+        .collect::<Vec<_>>();
+    (buttons, joltages)
+}
+
+fn p2(input: &str) -> Option<usize> {
+    parse(input)
+        .par_bridge()
+        .map(|machine| {
+            // The fewest presses is a small integer program.
+            let (buttons, joltages) = equations(&machine);
+            IntegerProgram::new(&buttons, &joltages)
+                .solve()
+                .map(|optimum| optimum.cost as usize)
+        })
+        .sum()
+}
+
+// The total button presses for the given free variables, if every press count comes out as a
+// non-negative whole number.
+fn presses(system: &LinearSystem, values: &[i128]) -> Option<i128> {
+    system
+        .pivot_values(values)
+        .try_fold(values.iter().sum(), |total, v| {
+            v.filter(|&v| v >= 0).map(|v| total + v)
+        })
+}
+
+fn dfs(system: &LinearSystem, idx: usize, values: &mut [i128], min: &mut i128, max: i128) {
+    // When we've assigned all free variables, check if it's a valid solution.
+    if idx == system.free().len() {
+        if let Some(total) = presses(system, values) {
+            *min = (*min).min(total);
+        }
+        return;
+    }
+
+    // Try different values for the current free variable.
+    let total: i128 = values[..idx].iter().sum();
+    for val in 0..max {
+        // Optimization: If we ever go above our min, we can't possibly do better.
+        if total + val >= *min {
+            break;
+        }
+        values[idx] = val;
+        dfs(system, idx + 1, values, min, max);
+    }
+}
+
+fn p2_dfs(input: &str) -> usize {
+    parse(input)
+        .par_bridge()
+        .map(|machine| {
+            let (buttons, joltages) = equations(&machine);
+            let system = LinearSystem::new(&buttons, &joltages);
+
+            // Now we can DFS over a much smaller solution space.
+            let max = *machine.joltages.iter().max().unwrap() as i128 + 1;
+            let mut min = i128::MAX;
+            let mut values = vec![0; system.free().len()];
+
+            dfs(&system, 0, &mut values, &mut min, max);
+
+            min as usize
+        })
+        .sum()
+}
+
+#[cfg(feature = "z3")]
+fn p2_z3(input: &str) -> usize {
+    parse(input)
+        .par_bridge()
+        .map(|machine| {
+            // Create the optimizer and some shared values.
+            let optimizer = Optimize::new();
+            let zero = Int::from_i64(0);
+
+            // Create our joltages.
+            let mut joltages = machine
+                .joltages
+                .iter()
+                .map(|_| Int::from_i64(0))
+                .collect::<Vec<_>>();
+
+            // Create our buttons and add them as expressions to our joltages.
+            let buttons = machine
+                .buttons
+                .iter()
+                .enumerate()
+                .map(|(i, button)| {
+                    // Create our integer for this buttons presses and assert it be greater than zero.
+                    let var = Int::fresh_const(&format!("button-{}", i));
+                    optimizer.assert(&var.ge(&zero));
+
+                    // Add this button to each of it's connect joltages.
+                    button.iter().for_each(|index| {
+                        joltages[*index] = &joltages[*index] + &var;
+                    });
+                    var
+                })
+                .collect::<Vec<_>>();
+
+            // Make all of joltages sum to the button presses.
+            joltages.iter().enumerate().for_each(|(i, joltage)| {
+                optimizer.assert(&joltage.eq(Int::from_i64(machine.joltages[i] as i64)))
+            });
+
+            // Tell the optimizer that we are optimizing on the minimum number of accumulated presses.
+            let total_presses = buttons.iter().fold(Int::from_i64(0), |acc, x| acc + x);
+            optimizer.minimize(&total_presses);
+
+            // Presumably this should work, so ignoring error checking. But we check to do the
+            // solve and then get the result and return it.
+            match optimizer.check(&[]) {
+                z3::SatResult::Sat => optimizer
+                    .get_model()
+                    .unwrap()
+                    .eval(&total_presses, true)
+                    .unwrap()
+                    .as_i64()
+                    .unwrap() as usize,
+                _ => unreachable!(),
+            }
+        })
+        .sum()
+}
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).ok_or("no way to reach the joltages")?.into())
+    }
+}
+
+pub const DAY: Day = Day::new(
+    10,
+    &[
+        Part::<Day10>::part1(),
+        Part::<Day10>::part2(),
+        Part::<Day10>::variant(2, "p2_dfs", |input| Ok(p2_dfs(input).into())),
+        // z3 needs libz3 to build, so it's only a variant with the z3 feature on.
+        #[cfg(feature = "z3")]
+        Part::<Day10>::variant(2, "p2_z3", |input| Ok(p2_z3(input).into())),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/day10-sample.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 7);
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(p2(INPUT), Some(33));
+
+        // Both counters go up together, so they can't end up different.
+        assert_eq!(p2("[..] (0,1) {1,2}"), None);
+    }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(INPUT).unwrap();
+    }
+
+    #[cfg(feature = "z3")]
+    #[test]
+    fn test_p2_z3() {
+        assert_eq!(p2_z3(INPUT), 33);
+    }
+}
@@ -1,10 +1,7 @@
-use std::time::Instant;
-
-use cached::proc_macro::cached;
+use aoc_common::{Answer, Day, Part, Result, Solution};
+use cached::{Cached, proc_macro::cached};
 use rustc_hash::FxHashMap;
 
-const INPUT: &str = include_str!("inputs/day11.txt");
-
 // Turn our input into a mapping of devices to their neighbors.
 fn parse(input: &str) -> FxHashMap<&str, Vec<&str>> {
     input
@@ -71,13 +68,14 @@ fn p2(input: &str) -> usize {
             * dp(&edges, &mut FxHashMap::default(), "dac", "out"))
 }
 
+// The cache is global, so the key has to own the node name.
 #[cached(
-    key = "(&'static str, bool, bool)",
-    convert = "{ (cur, seen_dac, seen_fft) }"
+    key = "(String, bool, bool)",
+    convert = "{ (cur.to_string(), seen_dac, seen_fft) }"
 )]
 fn dp_paulson(
-    neighbors: &FxHashMap<&'static str, Vec<&'static str>>,
-    cur: &'static str,
+    neighbors: &FxHashMap<&str, Vec<&str>>,
+    cur: &str,
     seen_dac: bool,
     seen_fft: bool,
 ) -> usize {
@@ -100,39 +98,59 @@ fn dp_paulson(
     }
 }
 
-fn p2_paulson(input: &'static str) -> usize {
+fn p2_paulson(input: &str) -> usize {
+    // Anything cached from a previous input would give us the wrong answer.
+    DP_PAULSON.lock().unwrap().cache_clear();
     let edges = parse(input);
     dp_paulson(&edges, "svr", false, false)
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
 
-    let now = Instant::now();
-    let solution = p2(INPUT);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
 
-    let now = Instant::now();
-    let solution = p2_paulson(INPUT);
-    println!("p2_paulson {:?} {}", now.elapsed(), solution);
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p2(input).into())
+    }
 }
 
+pub const DAY: Day = Day::new(
+    11,
+    &[
+        Part::<Day11>::part1(),
+        Part::<Day11>::part2(),
+        Part::<Day11>::variant(2, "p2_paulson", |input| Ok(p2_paulson(input).into())),
+    ],
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = include_str!("inputs/day11-sample.txt");
+    const INPUT: &str = include_str!("../inputs/day11-sample.txt");
 
     #[test]
     fn test_p1() {
         assert_eq!(p1(INPUT), 5);
     }
 
-    const INPUT2: &str = include_str!("inputs/day11-sample2.txt");
+    const INPUT2: &str = include_str!("../inputs/day11-sample2.txt");
     #[test]
     fn test_p2() {
         assert_eq!(p2(INPUT2), 2);
     }
+
+    #[test]
+    fn test_variants() {
+        DAY.check_variants(INPUT2).unwrap();
+    }
 }
@@ -0,0 +1,89 @@
+use aoc_common::{Answer, Day, Packing, Part, Polyomino, Result, Solution};
+use rayon::prelude::*;
+
+// 1. Read problem.
+// 2. Panic.
+// 3. Analyze input.
+// 4. Imagine puzzler's grin.
+// 5. Can we still try?
+
+// Interesting information:
+//
+// https://www.frontiersin.org/journals/mechanical-engineering/articles/10.3389/fmech.2022.966691/full
+
+struct Region {
+    width: usize,
+    height: usize,
+    shapes: Vec<usize>,
+}
+
+impl From<&str> for Region {
+    fn from(value: &str) -> Self {
+        let (dims, shapes) = value.split_once(": ").unwrap();
+        let (width, height) = dims.split_once("x").unwrap();
+
+        Self {
+            width: width.parse().unwrap(),
+            height: height.parse().unwrap(),
+            shapes: shapes
+                .split_whitespace()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+fn parse(input: &str) -> (Vec<Polyomino>, Vec<Region>) {
+    let chunks = input.trim().split("\n\n").collect::<Vec<_>>();
+
+    // Each shape starts with its index, which we can skip.
+    let shapes = chunks[..chunks.len() - 1]
+        .iter()
+        .map(|chunk| Polyomino::from(chunk.split_once('\n').unwrap().1))
+        .collect();
+    let regions = chunks[chunks.len() - 1].lines().map(Region::from).collect();
+
+    (shapes, regions)
+}
+
+fn p1(input: &str) -> usize {
+    // In the inputs we've seen, every region either doesn't have the area or has a box for each
+    // shape, so the packer never gets as far as searching. Only the sample needs the search.
+    let (shapes, regions) = parse(input);
+    regions
+        .par_iter()
+        .filter(|region| {
+            Packing::new(region.width, region.height, &shapes, &region.shapes)
+                .solve()
+                .is_some()
+        })
+        .count()
+}
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(p1(input).into())
+    }
+}
+
+pub const DAY: Day = Day::new(12, &[Part::<Day12>::part1()]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../inputs/day12-sample.txt");
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(p1(INPUT), 2);
+    }
+}
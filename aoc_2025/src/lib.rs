@@ -1,14 +1,32 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+use aoc_common::Year;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub const YEAR: Year = Year::new(
+    2025,
+    &[
+        day01::DAY,
+        day02::DAY,
+        day03::DAY,
+        day04::DAY,
+        day05::DAY,
+        day06::DAY,
+        day07::DAY,
+        day08::DAY,
+        day09::DAY,
+        day10::DAY,
+        day11::DAY,
+        day12::DAY,
+    ],
+);
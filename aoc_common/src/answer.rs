@@ -0,0 +1,134 @@
+use std::fmt::{self, Display};
+
+/// The result of solving a part. Most are numbers, but some days want a string and a few draw
+/// letters on a screen that we have to read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Letters read off of a screen. We keep the screen around in case a glyph wasn't recognized.
+    Ocr {
+        text: String,
+        screen: String,
+    },
+}
+
+impl Answer {
+    /// Read the letters off of a screen drawn with '#' and '.' using the font the puzzles use.
+    /// Each letter is 5 columns wide (including the spacing) and 6 rows tall. Unknown glyphs
+    /// become '?'.
+    pub fn ocr(screen: &str) -> Self {
+        let rows: Vec<&[u8]> = screen
+            .lines()
+            .map(|l| l.trim().as_bytes())
+            .filter(|l| !l.is_empty())
+            .collect();
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+
+        let mut text = String::new();
+        for start in (0..width).step_by(LETTER_WIDTH) {
+            let glyph: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    (start..start + LETTER_WIDTH)
+                        .map(|c| if r.get(c) == Some(&b'#') { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+
+            // Blank columns are just padding.
+            if glyph.iter().all(|r| !r.contains('#')) {
+                continue;
+            }
+
+            let glyph = glyph.join("\n");
+            let letter = FONT
+                .iter()
+                .find(|(_, pattern)| *pattern == glyph)
+                .map(|(letter, _)| *letter)
+                .unwrap_or('?');
+            text.push(letter);
+        }
+
+        Self::Ocr {
+            text,
+            screen: screen.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Ocr { text, .. } => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+const LETTER_WIDTH: usize = 5;
+
+// The glyphs we've seen so far. Most are 4 wide with a blank column but a few use all 5.
+const FONT: &[(char, &str)] = &[
+    ('A', ".##..\n#..#.\n#..#.\n####.\n#..#.\n#..#."),
+    ('B', "###..\n#..#.\n###..\n#..#.\n#..#.\n###.."),
+    ('C', ".##..\n#..#.\n#....\n#....\n#..#.\n.##.."),
+    ('E', "####.\n#....\n###..\n#....\n#....\n####."),
+    ('F', "####.\n#....\n###..\n#....\n#....\n#...."),
+    ('G', ".##..\n#..#.\n#....\n#.##.\n#..#.\n.###."),
+    ('H', "#..#.\n#..#.\n####.\n#..#.\n#..#.\n#..#."),
+    ('J', "..##.\n...#.\n...#.\n...#.\n#..#.\n.##.."),
+    ('K', "#..#.\n#.#..\n##...\n#.#..\n#.#..\n#..#."),
+    ('L', "#....\n#....\n#....\n#....\n#....\n####."),
+    ('O', ".##..\n#..#.\n#..#.\n#..#.\n#..#.\n.##.."),
+    ('P', "###..\n#..#.\n#..#.\n###..\n#....\n#...."),
+    ('R', "###..\n#..#.\n#..#.\n###..\n#.#..\n#..#."),
+    ('S', ".###.\n#....\n#....\n.##..\n...#.\n###.."),
+    ('U', "#..#.\n#..#.\n#..#.\n#..#.\n#..#.\n.##.."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####.\n...#.\n..#..\n.#...\n#....\n####."),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ocr() {
+        let screen = ".##..####.#..#.\n#..#.#....#..#.\n#..#.###..####.\n####.#....#..#.\n#..#.#....#..#.\n#..#.####.#..#.\n";
+        assert_eq!(Answer::ocr(screen).to_string(), "AEH");
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let screen = "#.#..\n.#...\n#.#..\n.....\n.....\n.....\n";
+        assert_eq!(Answer::ocr(screen).to_string(), "?");
+    }
+}
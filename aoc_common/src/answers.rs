@@ -0,0 +1,134 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+};
+
+use md5::{Digest, Md5};
+
+use crate::Result;
+
+// Everyone gets a different input, so answers are only valid for the input they came from.
+type Key = (u16, u8, u8, String);
+
+/// Known answers, stored one per line as `<year> <day> <part> <input hash> <answer>`.
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<Key, String>,
+}
+
+/// How a solution compares to what we know.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// The hash we use to tie an answer to its input.
+pub fn input_hash(input: &str) -> String {
+    Md5::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+impl Answers {
+    /// Load the answers file. A missing file just means we don't know anything yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(5, ' ');
+            let mut next = || {
+                fields
+                    .next()
+                    .ok_or(format!("{}:{}: expected 5 fields", path.display(), i + 1))
+            };
+            let (year, day, part, hash, answer) = (next()?, next()?, next()?, next()?, next()?);
+            answers.insert(
+                (year.parse()?, day.parse()?, part.parse()?, hash.to_string()),
+                answer.to_string(),
+            );
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, hash: &str, answer: &str) -> Status {
+        match self.answers.get(&(year, day, part, hash.to_string())) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+            None => Status::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, hash: &str, answer: &str) {
+        self.answers
+            .insert((year, day, part, hash.to_string()), answer.to_string());
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents: String = self
+            .answers
+            .iter()
+            .map(|((year, day, part, hash), answer)| {
+                format!("{year} {day} {part} {hash} {answer}\n")
+            })
+            .collect();
+        std::fs::write(&self.path, contents)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+        let hash = input_hash("1122\n");
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(2017, 1, 1, &hash, "3"), Status::Unknown);
+        answers.record(2017, 1, 1, &hash, "3");
+        answers.save().unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(answers.check(2017, 1, 1, &hash, "3"), Status::Pass);
+        assert_eq!(
+            answers.check(2017, 1, 1, &hash, "4"),
+            Status::Fail("3".to_string())
+        );
+        assert_eq!(
+            answers.check(2017, 1, 1, &input_hash("1234\n"), "3"),
+            Status::Unknown
+        );
+    }
+}
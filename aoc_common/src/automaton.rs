@@ -0,0 +1,449 @@
+use std::{mem, str::FromStr};
+
+use crate::{Point2, Result, nth};
+
+const BITS: usize = u64::BITS as usize;
+
+/// How a cell's next state comes from its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Dead cells with a number of live neighbors (out of the 8 around them) in `birth` come
+    /// alive, and live cells with a number in `survive` stay alive. Bit `n` is for `n` neighbors.
+    Totalistic { birth: u16, survive: u16 },
+
+    /// Each row on its own, where a cell's next state comes from its left neighbor, itself and
+    /// its right neighbor. Bit `4 * left + 2 * cell + right` is the next state, which is how
+    /// Wolfram numbers them.
+    Elementary(u8),
+}
+
+impl Rule {
+    /// Conway's Game of Life, B3/S23.
+    pub const LIFE: Self = Self::Totalistic {
+        birth: 1 << 3,
+        survive: 1 << 2 | 1 << 3,
+    };
+
+    /// A totalistic rule from whether a cell is alive and how many of its neighbors are.
+    pub fn totalistic(f: impl Fn(bool, usize) -> bool) -> Self {
+        let table = |alive| {
+            (0..=8)
+                .filter(|&n| f(alive, n))
+                .fold(0, |table, n| table | 1 << n)
+        };
+        Self::Totalistic {
+            birth: table(false),
+            survive: table(true),
+        }
+    }
+
+    /// An elementary rule from a cell's left neighbor, itself and its right neighbor.
+    pub fn elementary(f: impl Fn(bool, bool, bool) -> bool) -> Self {
+        Self::Elementary(
+            (0..8)
+                .filter(|&p| f(p & 4 != 0, p & 2 != 0, p & 1 != 0))
+                .fold(0, |rule, p| rule | 1 << p),
+        )
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Box<dyn std::error::Error>;
+
+    /// Parse a totalistic rule like "B3/S23".
+    fn from_str(s: &str) -> Result<Self> {
+        let counts = |part: &str, prefix: char| -> Result<u16> {
+            part.strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .ok_or_else(|| format!("invalid rule '{s}'"))?
+                .chars()
+                .try_fold(0, |counts, c| match c.to_digit(10) {
+                    Some(n) if n <= 8 => Ok(counts | 1 << n),
+                    _ => Err(format!("invalid rule '{s}'").into()),
+                })
+        };
+        let (birth, survive) = s
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("invalid rule '{s}'"))?;
+        Ok(Self::Totalistic {
+            birth: counts(birth, 'B')?,
+            survive: counts(survive, 'S')?,
+        })
+    }
+}
+
+/// A cellular automaton on a `width` by `height` board. Each row is packed into words so a step
+/// works out 64 cells at a time. Cells past the edges are dead unless the board wraps around.
+///
+/// <https://en.wikipedia.org/wiki/Cellular_automaton>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Automaton {
+    width: usize,
+    height: usize,
+    // Words per row.
+    stride: usize,
+    cells: Vec<u64>,
+    rule: Rule,
+    toroidal: bool,
+    // The cells that are stuck, and which of those are stuck on.
+    pinned: Vec<u64>,
+    pinned_on: Vec<u64>,
+}
+
+impl Automaton {
+    pub fn new(width: usize, height: usize, rule: Rule) -> Self {
+        let stride = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            stride,
+            cells: vec![0; stride * height],
+            rule,
+            toroidal: false,
+            pinned: vec![0; stride * height],
+            pinned_on: vec![0; stride * height],
+        }
+    }
+
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        rule: Rule,
+        mut f: impl FnMut(Point2<usize>) -> bool,
+    ) -> Self {
+        let mut automaton = Self::new(width, height, rule);
+        for y in 0..height {
+            for x in 0..width {
+                let p = Point2::new(x, y);
+                automaton.set(p, f(p));
+            }
+        }
+        automaton
+    }
+
+    /// Wrap the edges around, so the board is a torus.
+    pub fn toroidal(self) -> Self {
+        Self {
+            toroidal: true,
+            ..self
+        }
+    }
+
+    /// Keep a cell stuck on or off no matter what its neighbors do.
+    pub fn pin(mut self, p: Point2<usize>, on: bool) -> Self {
+        let (i, bit) = self.index(p);
+        self.pinned[i] |= bit;
+        match on {
+            true => self.pinned_on[i] |= bit,
+            false => self.pinned_on[i] &= !bit,
+        }
+        self.set(p, on);
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, p: Point2<usize>) -> (usize, u64) {
+        assert!(
+            p.x < self.width && p.y < self.height,
+            "{p} is off the board"
+        );
+        (p.y * self.stride + p.x / BITS, 1 << (p.x % BITS))
+    }
+
+    pub fn get(&self, p: Point2<usize>) -> bool {
+        let (i, bit) = self.index(p);
+        self.cells[i] & bit != 0
+    }
+
+    pub fn set(&mut self, p: Point2<usize>, on: bool) {
+        let (i, bit) = self.index(p);
+        match on {
+            true => self.cells[i] |= bit,
+            false => self.cells[i] &= !bit,
+        }
+    }
+
+    /// The number of live cells.
+    pub fn population(&self) -> usize {
+        self.cells.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.cells[y * self.stride..(y + 1) * self.stride]
+    }
+
+    // Each cell's left neighbor, lined up with word `w` of the row.
+    fn left(&self, row: &[u64], w: usize) -> u64 {
+        let carry = match w {
+            0 if self.toroidal => row[self.stride - 1] >> ((self.width - 1) % BITS) & 1,
+            0 => 0,
+            _ => row[w - 1] >> (BITS - 1),
+        };
+        row[w] << 1 | carry
+    }
+
+    // Each cell's right neighbor, lined up with word `w` of the row. What wraps around from the
+    // start of the row goes in the last cell, which isn't always the top bit.
+    fn right(&self, row: &[u64], w: usize) -> u64 {
+        let carry = match w + 1 == self.stride {
+            true if self.toroidal => (row[0] & 1) << ((self.width - 1) % BITS),
+            true => 0,
+            false => (row[w + 1] & 1) << (BITS - 1),
+        };
+        row[w] >> 1 | carry
+    }
+
+    pub fn step(&mut self) {
+        let empty = vec![0; self.stride];
+        let mut next = vec![0; self.cells.len()];
+        for y in 0..self.height {
+            let row = self.row(y);
+            let out = &mut next[y * self.stride..(y + 1) * self.stride];
+            match self.rule {
+                Rule::Elementary(rule) => {
+                    for (w, out) in out.iter_mut().enumerate() {
+                        *out = elementary(rule, self.left(row, w), row[w], self.right(row, w));
+                    }
+                }
+                Rule::Totalistic { birth, survive } => {
+                    let above = match y {
+                        0 if self.toroidal => self.row(self.height - 1),
+                        0 => &empty,
+                        _ => self.row(y - 1),
+                    };
+                    let below = match y + 1 == self.height {
+                        true if self.toroidal => self.row(0),
+                        true => &empty,
+                        false => self.row(y + 1),
+                    };
+                    for (w, out) in out.iter_mut().enumerate() {
+                        let neighbors = [
+                            self.left(above, w),
+                            above[w],
+                            self.right(above, w),
+                            self.left(row, w),
+                            self.right(row, w),
+                            self.left(below, w),
+                            below[w],
+                            self.right(below, w),
+                        ];
+                        *out = totalistic(birth, survive, row[w], neighbors);
+                    }
+                }
+            }
+
+            // Shifting can push cells past the end of the row.
+            if !self.width.is_multiple_of(BITS) {
+                out[self.stride - 1] &= (1 << (self.width % BITS)) - 1;
+            }
+        }
+
+        for ((cell, &pinned), &on) in next.iter_mut().zip(&self.pinned).zip(&self.pinned_on) {
+            *cell = *cell & !pinned | on;
+        }
+        self.cells = next;
+    }
+
+    /// Take `steps` steps. Once the board is back to something it's been before, the rest of the
+    /// loops are skipped.
+    pub fn run(&mut self, steps: usize) {
+        // Only the cells change, so they're all that needs remembering.
+        let cells = mem::take(&mut self.cells);
+        self.cells = nth(
+            cells,
+            |cells| {
+                self.cells.clone_from(cells);
+                self.step();
+                mem::take(&mut self.cells)
+            },
+            steps,
+        );
+    }
+}
+
+// Add the neighbors up bit-sliced, where counts[k] is bit k of every cell's count, and then pick
+// out the cells whose counts are in the rule.
+fn totalistic(birth: u16, survive: u16, alive: u64, neighbors: [u64; 8]) -> u64 {
+    let mut counts = [0u64; 4];
+    for neighbor in neighbors {
+        let mut carry = neighbor;
+        for count in &mut counts {
+            let sum = *count ^ carry;
+            carry &= *count;
+            *count = sum;
+        }
+    }
+
+    (0..=8).fold(0, |next, n| {
+        let exactly = counts
+            .iter()
+            .enumerate()
+            .fold(!0, |acc, (k, &count)| match n >> k & 1 {
+                1 => acc & count,
+                _ => acc & !count,
+            });
+        let born = if birth >> n & 1 == 1 { !alive } else { 0 };
+        let stays = if survive >> n & 1 == 1 { alive } else { 0 };
+        next | exactly & (born | stays)
+    })
+}
+
+// Pick out the cells whose left, center and right match one of the patterns the rule sets.
+fn elementary(rule: u8, left: u64, center: u64, right: u64) -> u64 {
+    (0..8).filter(|p| rule >> p & 1 == 1).fold(0, |next, p| {
+        let pick = |bit: u8, cells: u64| match p >> bit & 1 {
+            1 => cells,
+            _ => !cells,
+        };
+        next | pick(2, left) & pick(1, center) & pick(0, right)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, testing::Lcg};
+
+    fn board(input: &str, rule: Rule) -> Automaton {
+        let grid = Grid::from_bytes(input).unwrap();
+        Automaton::from_fn(grid.width(), grid.height(), rule, |p| grid[p] == b'#')
+    }
+
+    // Step a grid one cell at a time.
+    fn naive(grid: &Grid<bool>, rule: Rule, toroidal: bool) -> Grid<bool> {
+        let (w, h) = (grid.width() as isize, grid.height() as isize);
+        let alive = |x: isize, y: isize| match toroidal {
+            true => grid[Point2::new(x.rem_euclid(w) as usize, y.rem_euclid(h) as usize)],
+            false => {
+                (0..w).contains(&x)
+                    && (0..h).contains(&y)
+                    && grid[Point2::new(x as usize, y as usize)]
+            }
+        };
+        Grid::from_fn(grid.width(), grid.height(), |p| {
+            let (x, y) = (p.x as isize, p.y as isize);
+            match rule {
+                Rule::Totalistic { birth, survive } => {
+                    let n = (-1..=1)
+                        .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                        .filter(|&(dx, dy)| (dx, dy) != (0, 0) && alive(x + dx, y + dy))
+                        .count();
+                    let table = if grid[p] { survive } else { birth };
+                    table >> n & 1 == 1
+                }
+                Rule::Elementary(rule) => {
+                    let pattern =
+                        4 * alive(x - 1, y) as u8 + 2 * alive(x, y) as u8 + alive(x + 1, y) as u8;
+                    rule >> pattern & 1 == 1
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_rules() {
+        assert_eq!("B3/S23".parse::<Rule>().unwrap(), Rule::LIFE);
+        assert_eq!(
+            Rule::totalistic(|alive, n| n == 3 || alive && n == 2),
+            Rule::LIFE
+        );
+        assert_eq!(
+            "b36/s23".parse::<Rule>().unwrap(),
+            Rule::Totalistic {
+                birth: 1 << 3 | 1 << 6,
+                survive: 1 << 2 | 1 << 3
+            }
+        );
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("B9/S23".parse::<Rule>().is_err());
+        assert_eq!(Rule::elementary(|l, _, r| l != r), Rule::Elementary(90));
+    }
+
+    #[test]
+    fn test_against_naive() {
+        // Rows that fill a word exactly and that cross into the next, with both kinds of edges.
+        let mut rng = Lcg(42);
+        for width in [64, 70] {
+            let grid = Grid::from_fn(width, 6, |_| rng.next_u64() >> 62 == 0);
+            let rules = [
+                Rule::LIFE,
+                "B36/S125".parse().unwrap(),
+                Rule::Elementary(30),
+            ];
+            for (rule, toroidal) in rules.into_iter().flat_map(|r| [(r, false), (r, true)]) {
+                let mut automaton = Automaton::from_fn(width, 6, rule, |p| grid[p]);
+                if toroidal {
+                    automaton = automaton.toroidal();
+                }
+                let mut expected = grid.clone();
+                for _ in 0..10 {
+                    automaton.step();
+                    expected = naive(&expected, rule, toroidal);
+                }
+                let actual = Grid::from_fn(width, 6, |p| automaton.get(p));
+                assert!(actual == expected, "{width} {rule:?} toroidal: {toroidal}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lights() {
+        // The 2015 day 18 sample, with and without the corners stuck on.
+        let input = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+        let mut lights = board(input, Rule::LIFE);
+        lights.run(4);
+        assert_eq!(lights.population(), 4);
+
+        let mut lights = board(input, Rule::LIFE);
+        for p in [(0, 0), (5, 0), (0, 5), (5, 5)] {
+            lights = lights.pin(Point2::new(p.0, p.1), true);
+        }
+        lights.run(5);
+        assert_eq!(lights.population(), 17);
+    }
+
+    #[test]
+    fn test_cycles() {
+        // A blinker flips every step, so an odd number of steps leaves it on its side.
+        let mut blinker = board(".....\n.....\n.###.\n.....\n.....", Rule::LIFE);
+        let standing = board(".....\n..#..\n..#..\n..#..\n.....", Rule::LIFE);
+        blinker.run(1_000_000_000_001);
+        assert_eq!(blinker, standing);
+
+        // A glider gets back to where it started on a torus.
+        let glider = board(
+            ".#......\n..#.....\n###.....\n........\n........\n........\n........\n........",
+            Rule::LIFE,
+        )
+        .toroidal();
+        let mut moved = glider.clone();
+        moved.run(32);
+        assert_eq!(moved, glider);
+        moved.run(4);
+        assert_ne!(moved, glider);
+        assert_eq!(moved.population(), 5);
+    }
+
+    #[test]
+    fn test_traps() {
+        // The 2016 day 18 sample is rule 90, with each step the next row of tiles.
+        let row = ".^^.^.^^^^";
+        let mut traps = Automaton::from_fn(row.len(), 1, Rule::Elementary(90), |p| {
+            row.as_bytes()[p.x] == b'^'
+        });
+        let mut safe = 0;
+        for _ in 0..10 {
+            safe += traps.width() - traps.population();
+            traps.step();
+        }
+        assert_eq!(safe, 38);
+    }
+}
@@ -0,0 +1,253 @@
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, ShlAssign, Shr, ShrAssign,
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of bits sized at runtime. Setting a bit past the end grows the set; shifts keep the
+/// length and drop whatever falls off either end.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// A set of `len` bits, none of them set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(BITS)],
+            len,
+        }
+    }
+
+    /// The number of bits (set or not).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Grow or shrink to `len` bits. New bits aren't set.
+    pub fn resize(&mut self, len: usize) {
+        self.words.resize(len.div_ceil(BITS), 0);
+        self.len = len;
+        self.trim();
+    }
+
+    pub fn set(&mut self, index: usize) {
+        if index >= self.len {
+            self.resize(index + 1);
+        }
+        self.words[index / BITS] |= 1 << (index % BITS);
+    }
+
+    pub fn unset(&mut self, index: usize) {
+        if index < self.len {
+            self.words[index / BITS] &= !(1 << (index % BITS));
+        }
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        if index >= self.len {
+            self.resize(index + 1);
+        }
+        self.words[index / BITS] ^= 1 << (index % BITS);
+    }
+
+    pub fn test(&self, index: usize) -> bool {
+        index < self.len && self.words[index / BITS] & (1 << (index % BITS)) != 0
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Whether any bit is set.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// Whether no bit is set in both.
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// The indexes of the set bits in order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * BITS + bit)
+            })
+        })
+    }
+
+    // Clear the bits in the last word past the end so they can't leak into counts or comparisons.
+    fn trim(&mut self) {
+        if !self.len.is_multiple_of(BITS)
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1 << (self.len % BITS)) - 1;
+        }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::default();
+        for index in iter {
+            set.set(index);
+        }
+        set
+    }
+}
+
+/// Move every bit up `n` places (towards higher indexes).
+impl ShlAssign<usize> for BitSet {
+    fn shl_assign(&mut self, n: usize) {
+        let (words, bits) = (n / BITS, n % BITS);
+        for i in (0..self.words.len()).rev() {
+            let word = |j: usize| i.checked_sub(j).map_or(0, |j| self.words[j]);
+            self.words[i] = match bits {
+                0 => word(words),
+                _ => word(words) << bits | word(words + 1) >> (BITS - bits),
+            };
+        }
+        self.trim();
+    }
+}
+
+/// Move every bit down `n` places (towards lower indexes).
+impl ShrAssign<usize> for BitSet {
+    fn shr_assign(&mut self, n: usize) {
+        let (words, bits) = (n / BITS, n % BITS);
+        for i in 0..self.words.len() {
+            let word = |j: usize| self.words.get(i + j).copied().unwrap_or(0);
+            self.words[i] = match bits {
+                0 => word(words),
+                _ => word(words) >> bits | word(words + 1) << (BITS - bits),
+            };
+        }
+    }
+}
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shl(self, n: usize) -> BitSet {
+        let mut set = self.clone();
+        set <<= n;
+        set
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+
+    fn shr(self, n: usize) -> BitSet {
+        let mut set = self.clone();
+        set >>= n;
+        set
+    }
+}
+
+// Bitwise operators between sets. The result is as long as the longer of the two.
+macro_rules! bitwise {
+    ($op:ident, $f:ident, $assign:ident, $assign_f:ident, $tok:tt) => {
+        impl $assign<&BitSet> for BitSet {
+            fn $assign_f(&mut self, rhs: &BitSet) {
+                if rhs.len > self.len {
+                    self.resize(rhs.len);
+                }
+                for (i, word) in self.words.iter_mut().enumerate() {
+                    *word = *word $tok rhs.words.get(i).copied().unwrap_or(0);
+                }
+            }
+        }
+
+        impl $op for &BitSet {
+            type Output = BitSet;
+
+            fn $f(self, rhs: &BitSet) -> BitSet {
+                let mut set = self.clone();
+                set.$assign_f(rhs);
+                set
+            }
+        }
+    };
+}
+
+bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let mut set = BitSet::new(200);
+        for i in [0, 63, 64, 127, 128, 129, 199] {
+            set.set(i);
+        }
+        assert!(set.test(128));
+        assert!(!set.test(130));
+        assert_eq!(set.count_ones(), 7);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [0, 63, 64, 127, 128, 129, 199]
+        );
+
+        set.unset(128);
+        set.toggle(129);
+        set.toggle(5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 5, 63, 64, 127, 199]);
+
+        // Setting past the end grows the set, testing past it is just false.
+        set.set(300);
+        assert_eq!(set.len(), 301);
+        assert!(set.test(300) && !set.test(1000));
+        set.resize(100);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 5, 63, 64]);
+    }
+
+    #[test]
+    fn test_shift() {
+        let set: BitSet = [0, 1, 63, 70].into_iter().collect();
+        let mut wide = set.clone();
+        wide.resize(140);
+        assert_eq!((&wide << 65).iter().collect::<Vec<_>>(), [65, 66, 128, 135]);
+        assert_eq!((&wide >> 1).iter().collect::<Vec<_>>(), [0, 62, 69]);
+        assert_eq!((&wide >> 64).iter().collect::<Vec<_>>(), [6]);
+
+        // Bits shifted off the end are gone.
+        assert_eq!((&set << 1).iter().collect::<Vec<_>>(), [1, 2, 64]);
+        assert_eq!((&(&set << 1) >> 1).iter().collect::<Vec<_>>(), [0, 1, 63]);
+    }
+
+    #[test]
+    fn test_ops() {
+        let a: BitSet = [1, 2, 100].into_iter().collect();
+        let b: BitSet = [2, 3].into_iter().collect();
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [2]);
+        assert_eq!((&a | &b).iter().collect::<Vec<_>>(), [1, 2, 3, 100]);
+        assert_eq!((&b ^ &a).iter().collect::<Vec<_>>(), [1, 3, 100]);
+        assert_eq!((&b ^ &a).len(), 101);
+        assert!(!(&a & &BitSet::new(10)).any());
+        assert!(!a.is_disjoint(&b));
+        assert!(a.is_disjoint(&[3, 99].into_iter().collect()));
+    }
+}
@@ -0,0 +1,353 @@
+use std::{
+    collections::VecDeque,
+    ops::{Deref, Range},
+};
+
+use crate::{Cell, Coord, Grid, Point2, Result};
+
+/// A compressed axis. Every interesting coordinate gets a slot of its own, each run of
+/// coordinates between them shares one, and there's an empty slot at either end so anything
+/// built on top has a border to work from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    // Where each slot starts, followed by where the last one ends.
+    starts: Vec<isize>,
+}
+
+impl Axis {
+    pub fn new(coords: impl IntoIterator<Item = isize>) -> Self {
+        let mut coords: Vec<isize> = coords.into_iter().collect();
+        coords.sort_unstable();
+        coords.dedup();
+        let (Some(&min), Some(&max)) = (coords.first(), coords.last()) else {
+            return Self { starts: vec![] };
+        };
+
+        let mut starts = vec![];
+        let mut end = None;
+        for c in [min - 1].into_iter().chain(coords).chain([max + 1]) {
+            // Only runs that have something in them get a slot.
+            if let Some(end) = end
+                && end < c
+            {
+                starts.push(end);
+            }
+            starts.push(c);
+            end = Some(c + 1);
+        }
+        starts.extend(end);
+        Self { starts }
+    }
+
+    /// The number of slots.
+    pub fn len(&self) -> usize {
+        self.starts.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The slot an original coordinate falls in.
+    pub fn slot(&self, v: isize) -> Option<usize> {
+        match (self.starts.first(), self.starts.last()) {
+            (Some(&first), Some(&last)) if first <= v && v < last => {
+                Some(self.starts.partition_point(|&s| s <= v) - 1)
+            }
+            _ => None,
+        }
+    }
+
+    /// The original coordinates a slot covers.
+    pub fn range(&self, slot: usize) -> Range<isize> {
+        self.starts[slot]..self.starts[slot + 1]
+    }
+
+    pub fn width(&self, slot: usize) -> u64 {
+        (self.starts[slot + 1] - self.starts[slot]) as u64
+    }
+
+    // The slots from `from` to `to` (inclusive, clipped to the axis) as runs of slots. The first
+    // and last slot are on their own along with how much of them is covered.
+    fn spans(&self, from: isize, to: isize) -> Vec<(usize, usize, Option<u64>)> {
+        let (Some(&first), Some(&last)) = (self.starts.first(), self.starts.last()) else {
+            return vec![];
+        };
+        let (from, to) = (from.max(first), to.min(last - 1));
+        if from > to {
+            return vec![];
+        }
+
+        let (s1, s2) = (self.slot(from).unwrap(), self.slot(to).unwrap());
+        if s1 == s2 {
+            return vec![(s1, s1, Some((to - from + 1) as u64))];
+        }
+        let mut spans = vec![(s1, s1, Some((self.range(s1).end - from) as u64))];
+        if s1 + 1 < s2 {
+            spans.push((s1 + 1, s2 - 1, None));
+        }
+        spans.push((s2, s2, Some((to - self.range(s2).start + 1) as u64)));
+        spans
+    }
+}
+
+/// Sums over any rectangle of a grid in constant time.
+#[derive(Debug, Clone)]
+pub struct PrefixSum2<T> {
+    // sums[(r + 1, c + 1)] is the sum of everything up to and including (r, c).
+    sums: Grid<T>,
+}
+
+impl<T: Coord> PrefixSum2<T> {
+    pub fn new<S: Deref<Target = [T]>>(grid: &Grid<T, S>) -> Self {
+        let mut sums = Grid::new(grid.width() + 1, grid.height() + 1, T::ZERO);
+        for (p, v) in grid.iter() {
+            let (r, c) = (p.y, p.x);
+            sums[(r + 1, c + 1)] = sums[(r, c + 1)] + sums[(r + 1, c)] - sums[(r, c)] + *v;
+        }
+        Self { sums }
+    }
+
+    /// The sum of the cells between two corners (inclusive).
+    pub fn sum(&self, a: Point2<usize>, b: Point2<usize>) -> T {
+        let (r1, r2) = (a.y.min(b.y), a.y.max(b.y) + 1);
+        let (c1, c2) = (a.x.min(b.x), a.x.max(b.x) + 1);
+        self.sums[(r2, c2)] + self.sums[(r1, c1)] - self.sums[(r1, c2)] - self.sums[(r2, c1)]
+    }
+}
+
+/// What a cell of a compressed plane is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    Outside,
+    Boundary,
+    Inside,
+}
+
+impl Cell for Region {
+    fn to_char(&self) -> char {
+        match self {
+            Region::Outside => '.',
+            Region::Boundary => '#',
+            Region::Inside => 'X',
+        }
+    }
+}
+
+/// A plane squeezed down to the coordinates of the shapes on it, so huge coordinates become a
+/// small grid of regions. Areas are tracked in original units, so "how much of this rectangle
+/// is covered" (and "is all of it") are constant time no matter where the corners fall.
+#[derive(Debug, Clone)]
+pub struct CompressedPlane {
+    xs: Axis,
+    ys: Axis,
+    regions: Grid<Region>,
+    covered: PrefixSum2<u64>,
+}
+
+impl CompressedPlane {
+    /// The inside of a rectilinear polygon given by its corners in order. The boundary counts as
+    /// covered.
+    pub fn from_polygon(vertices: &[Point2<isize>]) -> Result<Self> {
+        if vertices.is_empty() {
+            return Err("the polygon has no vertices".into());
+        }
+        let xs = Axis::new(vertices.iter().map(|p| p.x));
+        let ys = Axis::new(vertices.iter().map(|p| p.y));
+        let mut regions = Grid::new(xs.len(), ys.len(), Region::Inside);
+
+        for (a, b) in vertices.iter().zip(vertices.iter().cycle().skip(1)) {
+            if a.x != b.x && a.y != b.y {
+                return Err(
+                    format!("the edge from {a} to {b} isn't horizontal or vertical").into(),
+                );
+            }
+            let (a, b) = (slot(&xs, &ys, *a), slot(&xs, &ys, *b));
+            for r in a.y.min(b.y)..=a.y.max(b.y) {
+                for c in a.x.min(b.x)..=a.x.max(b.x) {
+                    regions[(r, c)] = Region::Boundary;
+                }
+            }
+        }
+
+        // Flood the outside from a corner. The empty slots at the ends of the axes make sure it
+        // can get all the way around.
+        let mut frontier = VecDeque::from([Point2::new(0, 0)]);
+        regions[Point2::new(0, 0)] = Region::Outside;
+        while let Some(p) = frontier.pop_front() {
+            for n in regions.neighbors4(p) {
+                if regions[n] == Region::Inside {
+                    regions[n] = Region::Outside;
+                    frontier.push_back(n);
+                }
+            }
+        }
+
+        Ok(Self::new(xs, ys, regions))
+    }
+
+    /// The union of rectangles given by opposite corners (inclusive).
+    pub fn from_rects(rects: &[(Point2<isize>, Point2<isize>)]) -> Result<Self> {
+        if rects.is_empty() {
+            return Err("there are no rectangles".into());
+        }
+        let xs = Axis::new(rects.iter().flat_map(|(a, b)| [a.x, b.x]));
+        let ys = Axis::new(rects.iter().flat_map(|(a, b)| [a.y, b.y]));
+        let mut regions = Grid::new(xs.len(), ys.len(), Region::Outside);
+
+        for (a, b) in rects {
+            let (a, b) = (slot(&xs, &ys, *a), slot(&xs, &ys, *b));
+            for r in a.y.min(b.y)..=a.y.max(b.y) {
+                for c in a.x.min(b.x)..=a.x.max(b.x) {
+                    regions[(r, c)] = Region::Inside;
+                }
+            }
+        }
+
+        Ok(Self::new(xs, ys, regions))
+    }
+
+    fn new(xs: Axis, ys: Axis, regions: Grid<Region>) -> Self {
+        let areas = Grid::from_fn(regions.width(), regions.height(), |p| match regions[p] {
+            Region::Outside => 0,
+            _ => xs.width(p.x) * ys.width(p.y),
+        });
+        Self {
+            covered: PrefixSum2::new(&areas),
+            xs,
+            ys,
+            regions,
+        }
+    }
+
+    pub fn xs(&self) -> &Axis {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &Axis {
+        &self.ys
+    }
+
+    /// The compressed cells, indexed by slot.
+    pub fn regions(&self) -> &Grid<Region> {
+        &self.regions
+    }
+
+    /// The compressed cell an original point falls in.
+    pub fn slot(&self, p: Point2<isize>) -> Option<Point2<usize>> {
+        Some(Point2::new(self.xs.slot(p.x)?, self.ys.slot(p.y)?))
+    }
+
+    pub fn region(&self, p: Point2<isize>) -> Region {
+        self.slot(p).map_or(Region::Outside, |s| self.regions[s])
+    }
+
+    /// How much of the rectangle between two corners (inclusive) is covered.
+    pub fn area(&self, a: Point2<isize>, b: Point2<isize>) -> u64 {
+        let xs = self.xs.spans(a.x.min(b.x), a.x.max(b.x));
+        let ys = self.ys.spans(a.y.min(b.y), a.y.max(b.y));
+
+        let mut area = 0;
+        for &(c1, c2, width) in &xs {
+            for &(r1, r2, height) in &ys {
+                // Partly covered slots on the edges are scaled down to how much of them is in the
+                // rectangle. Each one is a single slot, so the division is exact.
+                let mut sum = self.covered.sum(Point2::new(c1, r1), Point2::new(c2, r2));
+                if let Some(width) = width {
+                    sum = sum / self.xs.width(c1) * width;
+                }
+                if let Some(height) = height {
+                    sum = sum / self.ys.width(r1) * height;
+                }
+                area += sum;
+            }
+        }
+        area
+    }
+
+    /// Whether all of the rectangle between two corners (inclusive) is covered.
+    pub fn contains_rect(&self, a: Point2<isize>, b: Point2<isize>) -> bool {
+        let size = (a.x.abs_diff(b.x) as u64 + 1) * (a.y.abs_diff(b.y) as u64 + 1);
+        self.area(a, b) == size
+    }
+}
+
+// The slot of a point we know is on the axes.
+fn slot(xs: &Axis, ys: &Axis, p: Point2<isize>) -> Point2<usize> {
+    Point2::new(xs.slot(p.x).unwrap(), ys.slot(p.y).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis() {
+        let axis = Axis::new([10, 3, 4, 10]);
+        // 2 | 3 | 4 | 5..=9 | 10 | 11
+        assert_eq!(axis.len(), 6);
+        assert_eq!(axis.slot(3), Some(1));
+        assert_eq!(axis.slot(7), Some(3));
+        assert_eq!(axis.range(3), 5..10);
+        assert_eq!(axis.width(3), 5);
+        assert_eq!(axis.slot(1), None);
+        assert_eq!(axis.slot(12), None);
+        assert!(Axis::new([]).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_sum() {
+        let grid = Grid::from_fn(4, 3, |p| p.x + p.y * 4);
+        let sums = PrefixSum2::new(&grid);
+        assert_eq!(sums.sum(Point2::new(0, 0), Point2::new(3, 2)), 66);
+        assert_eq!(
+            sums.sum(Point2::new(2, 2), Point2::new(1, 1)),
+            5 + 6 + 9 + 10
+        );
+        assert_eq!(sums.sum(Point2::new(3, 0), Point2::new(3, 0)), 3);
+    }
+
+    #[test]
+    fn test_polygon() {
+        // An L: a 10x10 square missing its top right 5x5.
+        let plane = CompressedPlane::from_polygon(&[
+            Point2::new(0, 0),
+            Point2::new(4, 0),
+            Point2::new(4, 5),
+            Point2::new(9, 5),
+            Point2::new(9, 9),
+            Point2::new(0, 9),
+        ])
+        .unwrap();
+        assert_eq!(plane.area(Point2::new(-5, -5), Point2::new(20, 20)), 75);
+        assert_eq!(
+            plane.area(Point2::new(2, 2), Point2::new(7, 7)),
+            3 * 3 + 6 * 3
+        );
+        assert!(plane.contains_rect(Point2::new(0, 9), Point2::new(4, 0)));
+        assert!(plane.contains_rect(Point2::new(1, 6), Point2::new(8, 8)));
+        assert!(!plane.contains_rect(Point2::new(0, 0), Point2::new(5, 5)));
+        assert_eq!(plane.region(Point2::new(2, 2)), Region::Inside);
+        assert_eq!(plane.region(Point2::new(4, 2)), Region::Boundary);
+        assert_eq!(plane.region(Point2::new(7, 2)), Region::Outside);
+        assert_eq!(plane.region(Point2::new(70, 2)), Region::Outside);
+
+        assert!(CompressedPlane::from_polygon(&[Point2::new(0, 0), Point2::new(1, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_rects() {
+        let plane = CompressedPlane::from_rects(&[
+            (Point2::new(0, 0), Point2::new(9, 9)),
+            (Point2::new(5, 5), Point2::new(14, 14)),
+        ])
+        .unwrap();
+        assert_eq!(plane.area(Point2::new(0, 0), Point2::new(14, 14)), 175);
+        assert!(plane.contains_rect(Point2::new(3, 5), Point2::new(12, 8)));
+        assert!(!plane.contains_rect(Point2::new(3, 3), Point2::new(12, 12)));
+        assert_eq!(
+            plane.regions().to_string(),
+            ".........\n.XXXXX...\n.XXXXX...\n.XXXXXXX.\n.XXXXXXX.\n.XXXXXXX.\n...XXXXX.\n...XXXXX.\n.........\n"
+        );
+    }
+}
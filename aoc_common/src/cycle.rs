@@ -0,0 +1,199 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// Where the sequence `x0, f(x0), f(f(x0)), ...` starts repeating: the state after `start` steps
+/// comes back every `period` steps after that.
+///
+/// <https://en.wikipedia.org/wiki/Cycle_detection>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step before the end of the first loop that has the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+
+    /// Extrapolate a counter out to step `n` when it goes up by the same amount each time around
+    /// the loop. `value(i)` only gets asked about steps up to `start + period`.
+    pub fn extrapolate(&self, n: usize, mut value: impl FnMut(usize) -> i64) -> i64 {
+        if n <= self.start + self.period {
+            return value(n);
+        }
+        let laps = ((n - self.start) / self.period) as i64;
+        let per_lap = value(self.start + self.period) - value(self.start);
+        value(self.reduce(n)) + laps * per_lap
+    }
+}
+
+/// Brent's algorithm. It only keeps two states around, but steps through the sequence a few
+/// times. Never returns if the sequence doesn't repeat.
+pub fn brent<T: Clone + PartialEq>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    // Move the tortoise up to the hare at each power of two until the hare runs into it.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = f(&hare);
+        period += 1;
+    }
+
+    // Start them a period apart and they meet where the loop starts.
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..period {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Floyd's tortoise and hare. Like `brent`, but usually slower.
+pub fn floyd<T: Clone + PartialEq>(x0: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        hare = f(&hare);
+    }
+
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Step through the sequence once, remembering each state's `key`, until a key comes back. Keys
+/// can leave out things like counters that keep going up even though the rest repeats.
+///
+/// Along with the cycle, returns every state up to `start + period`, which has the same key as
+/// `start`.
+pub fn find_cycle<T, K: Hash + Eq>(
+    x0: T,
+    mut f: impl FnMut(&T) -> T,
+    mut key: impl FnMut(&T) -> K,
+) -> (Cycle, Vec<T>) {
+    let mut seen = FxHashMap::default();
+    let mut states = vec![x0];
+    loop {
+        let step = states.len() - 1;
+        if let Some(start) = seen.insert(key(&states[step]), step) {
+            let period = step - start;
+            return (Cycle { start, period }, states);
+        }
+        states.push(f(&states[step]));
+    }
+}
+
+/// The state after `n` steps, skipping over the loops once the states start repeating.
+pub fn nth<T: Clone + Hash + Eq>(x0: T, mut f: impl FnMut(&T) -> T, n: usize) -> T {
+    let mut seen = FxHashMap::default();
+    let mut states = vec![];
+    let mut state = x0;
+    for step in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: step - start,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        let next = f(&state);
+        seen.insert(state.clone(), step);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4 and then 5 to 11 over and over.
+    fn rho(&x: &u64) -> u64 {
+        match x {
+            11 => 5,
+            _ => x + 1,
+        }
+    }
+
+    fn cycle(start: usize, period: usize) -> Cycle {
+        Cycle { start, period }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let expected = cycle(5, 7);
+        assert_eq!(brent(0, rho), expected);
+        assert_eq!(floyd(0, rho), expected);
+        assert_eq!(find_cycle(0, rho, |&x| x).0, expected);
+
+        // Straight into the loop, and a fixed point.
+        assert_eq!(brent(5, rho), cycle(0, 7));
+        assert_eq!(floyd(3, |_| 3), cycle(0, 1));
+        assert_eq!(brent(3, |_| 3), cycle(0, 1));
+
+        // A pseudo random sequence mod a prime.
+        let f = |&x: &u64| (x * x + 1) % 10007;
+        let (found, states) = find_cycle(2, f, |&x| x);
+        assert_eq!(brent(2, f), found);
+        assert_eq!(floyd(2, f), found);
+        assert_eq!(states.len(), found.start + found.period + 1);
+        assert_eq!(states[found.start], states[found.start + found.period]);
+    }
+
+    #[test]
+    fn test_nth() {
+        assert_eq!(nth(0, rho, 3), 3);
+        assert_eq!(nth(0, rho, 12), 5);
+        assert_eq!(
+            nth(0, rho, 1_000_000_000_000),
+            5 + (1_000_000_000_000 - 5) % 7
+        );
+        assert_eq!(nth(7, |_| 7, usize::MAX), 7);
+        assert_eq!(nth(0, rho, 0), 0);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A counter that goes up by one every step, where only the position in the loop repeats.
+        let step = |&(x, count): &(u64, i64)| (rho(&x), count + 1);
+        let (found, states) = find_cycle((0, 0), step, |&(x, _)| x);
+        assert_eq!(found, cycle(5, 7));
+        let n = 1_000_000_000_000;
+        assert_eq!(found.extrapolate(n, |i| states[i].1), n as i64);
+        assert_eq!(found.extrapolate(9, |i| states[i].1), 9);
+        assert_eq!(found.reduce(n), 5 + (n - 5) % 7);
+        assert_eq!(found.reduce(3), 3);
+    }
+}
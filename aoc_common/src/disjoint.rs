@@ -0,0 +1,329 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// Union-find over `0..len` with union by size.
+///
+/// Finds compress paths unless rollback is on: then unions can be undone back to a snapshot (for
+/// offline algorithms), and union by size alone keeps the trees shallow.
+///
+/// <https://en.wikipedia.org/wiki/Disjoint-set_data_structure>
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // The size of each set. Only accurate for the roots.
+    size: Vec<usize>,
+    count: usize,
+    // The roots that were attached to another root, in order, when rollback is on.
+    history: Option<Vec<usize>>,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+            history: None,
+        }
+    }
+
+    pub fn with_rollback(len: usize) -> Self {
+        Self {
+            history: Some(vec![]),
+            ..Self::new(len)
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Add a new element in a set of its own and return its index.
+    pub fn make_set(&mut self) -> usize {
+        self.parent.push(self.parent.len());
+        self.size.push(1);
+        self.count += 1;
+        self.parent.len() - 1
+    }
+
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way straight at the root.
+        if self.history.is_none() {
+            let mut i = i;
+            while self.parent[i] != root {
+                let next = self.parent[i];
+                self.parent[i] = root;
+                i = next;
+            }
+        }
+        root
+    }
+
+    /// Merge the sets of `i` and `j`. Returns false if they were already the same set.
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (i, j) = (self.find(i), self.find(j));
+        if i == j {
+            return false;
+        }
+
+        let (child, root) = match self.size[i] < self.size[j] {
+            true => (i, j),
+            false => (j, i),
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.count -= 1;
+        if let Some(history) = &mut self.history {
+            history.push(child);
+        }
+        true
+    }
+
+    pub fn connected(&mut self, i: usize, j: usize) -> bool {
+        self.find(i) == self.find(j)
+    }
+
+    /// The size of the set `i` is in.
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.size[root]
+    }
+
+    /// One element (the root) from each set.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|&i| self.parent[i] == i)
+    }
+
+    /// The size of each set.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.roots().map(|root| self.size[root])
+    }
+
+    /// The members of each set. Sets are ordered by their smallest member.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = FxHashMap::default();
+        let mut components: Vec<Vec<usize>> = vec![];
+        for i in 0..self.len() {
+            let root = self.find(i);
+            let c = *index.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[c].push(i);
+        }
+        components
+    }
+
+    /// A point to roll back to. Panics if rollback isn't on.
+    pub fn snapshot(&self) -> usize {
+        self.history
+            .as_ref()
+            .expect("rollback isn't on for this set")
+            .len()
+    }
+
+    /// Undo every union since the snapshot.
+    pub fn rollback(&mut self, snapshot: usize) {
+        let history = self
+            .history
+            .as_mut()
+            .expect("rollback isn't on for this set");
+        while history.len() > snapshot {
+            let child = history.pop().unwrap();
+            let root = self.parent[child];
+            self.parent[child] = child;
+            self.size[root] -= self.size[child];
+            self.count += 1;
+        }
+    }
+}
+
+/// A `DisjointSet` over any hashable labels. Labels are added the first time they're seen.
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    set: DisjointSet,
+    index: FxHashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self {
+            set: DisjointSet::new(0),
+            index: FxHashMap::default(),
+            keys: vec![],
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rollback() -> Self {
+        Self {
+            set: DisjointSet::with_rollback(0),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn count(&self) -> usize {
+        self.set.count()
+    }
+
+    /// The index of a label, adding it in a set of its own if it's new.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+        let i = self.set.make_set();
+        self.index.insert(key.clone(), i);
+        self.keys.push(key);
+        i
+    }
+
+    pub fn index(&self, key: &K) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+
+    pub fn key(&self, i: usize) -> &K {
+        &self.keys[i]
+    }
+
+    /// The label representing the set `key` is in.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let root = self.set.find(self.index(key)?);
+        Some(&self.keys[root])
+    }
+
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.set.union(a, b)
+    }
+
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.index(a), self.index(b)) {
+            (Some(a), Some(b)) => self.set.connected(a, b),
+            _ => a == b,
+        }
+    }
+
+    pub fn size(&mut self, key: &K) -> usize {
+        self.index(key).map_or(0, |i| self.set.size(i))
+    }
+
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.set.sizes()
+    }
+
+    /// The labels in each set, in the order they were added.
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.set
+            .components()
+            .into_iter()
+            .map(|c| c.into_iter().map(|i| &self.keys[i]).collect())
+            .collect()
+    }
+
+    pub fn snapshot(&self) -> usize {
+        self.set.snapshot()
+    }
+
+    /// Undo every union since the snapshot. Labels added since then stay, in sets of their own.
+    pub fn rollback(&mut self, snapshot: usize) {
+        self.set.rollback(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut set = DisjointSet::new(6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert_eq!(set.count(), 3);
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.components(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+
+        let mut sizes: Vec<_> = set.sizes().collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 4]);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // A find on a long chain shouldn't blow the stack.
+        let n = 1_000_000;
+        let mut set = DisjointSet {
+            parent: (0..n).map(|i: usize| i.saturating_sub(1)).collect(),
+            size: vec![1; n],
+            count: 1,
+            history: None,
+        };
+        assert_eq!(set.find(n - 1), 0);
+        assert_eq!(set.parent[n - 2], 0);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut set = DisjointSet::with_rollback(4);
+        set.union(0, 1);
+        let snapshot = set.snapshot();
+        set.union(1, 2);
+        set.union(2, 3);
+        assert_eq!((set.count(), set.size(0)), (1, 4));
+
+        set.rollback(snapshot);
+        assert_eq!((set.count(), set.size(0)), (3, 2));
+        assert!(set.connected(0, 1) && !set.connected(1, 2));
+        set.rollback(0);
+        assert_eq!(set.count(), 4);
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut set = KeyedDisjointSet::new();
+        set.union("a", "b");
+        set.union("c", "d");
+        set.insert("e");
+        set.union("b", "d");
+        assert_eq!(set.count(), 2);
+        assert_eq!(set.size(&"c"), 4);
+        assert!(set.connected(&"a", &"c"));
+        assert!(!set.connected(&"a", &"z"));
+        assert_eq!(set.find(&"d").cloned(), set.find(&"a").cloned());
+        assert_eq!(set.components(), [vec![&"a", &"b", &"c", &"d"], vec![&"e"]]);
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::{Deref, DerefMut, Index, IndexMut},
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{Point2, Result};
+
+/// A grid straight out of the input: it borrows the bytes, newlines and all.
+pub type ByteGrid<'a> = Grid<u8, &'a [u8]>;
+
+/// A dense, row-major grid. Points are `x` = column and `y` = row. The cells can be owned (the
+/// default) or borrowed, in which case rows may be `stride` apart rather than `width` (e.g. the
+/// newline at the end of each line of the input).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, S = Vec<T>> {
+    cells: S,
+    width: usize,
+    height: usize,
+    stride: usize,
+    cell: PhantomData<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(width, vec![fill; width * height])
+    }
+
+    /// A grid from row-major cells.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        Self {
+            height: cells.len() / width,
+            width,
+            stride: width,
+            cells,
+            cell: PhantomData,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point2<usize>) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self::from_vec(width, cells)
+    }
+}
+
+impl<'a> Grid<u8, &'a [u8]> {
+    /// View the lines of the input as a grid without copying it. Every line has to be the same
+    /// length; trailing newlines are ignored.
+    pub fn from_bytes<B: AsRef<[u8]> + ?Sized>(input: &'a B) -> Result<Self> {
+        let mut cells = input.as_ref();
+        while let [rest @ .., b'\n'] = cells {
+            cells = rest;
+        }
+        if cells.is_empty() {
+            return Err("the grid is empty".into());
+        }
+
+        let width = cells
+            .iter()
+            .position(|&b| b == b'\n')
+            .unwrap_or(cells.len());
+        for (row, line) in cells.split(|&b| b == b'\n').enumerate() {
+            if line.len() != width {
+                return Err(format!(
+                    "line {} is {} wide but the first line is {width}",
+                    row + 1,
+                    line.len()
+                )
+                .into());
+            }
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height: cells.len().div_ceil(width + 1),
+            stride: width + 1,
+            cell: PhantomData,
+        })
+    }
+}
+
+impl<T, S: Deref<Target = [T]>> Grid<T, S> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, p: Point2<usize>) -> Option<usize> {
+        self.contains(p).then(|| p.y * self.stride + p.x)
+    }
+
+    pub fn contains(&self, p: Point2<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point2<usize>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.stride..row * self.stride + self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.rows().map(move |row| &row[col])
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2<usize>> + use<T, S> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.points().map(|p| (p, &self[p]))
+    }
+
+    /// The orthogonal neighbors of a point that are inside the grid.
+    pub fn neighbors4(&self, p: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + use<T, S> {
+        let (width, height) = (self.width, self.height);
+        p.neighbors4().filter(move |n| n.x < width && n.y < height)
+    }
+
+    /// The neighbors of a point, including diagonals, that are inside the grid.
+    pub fn neighbors8(&self, p: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + use<T, S> {
+        let (width, height) = (self.width, self.height);
+        p.neighbors8().filter(move |n| n.x < width && n.y < height)
+    }
+
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Point2<usize>> {
+        self.iter().find(|(_, v)| f(v)).map(|(p, _)| p)
+    }
+
+    /// The first point (row by row) holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point2<usize>>
+    where
+        T: PartialEq,
+    {
+        self.position(|v| v == value)
+    }
+
+    pub fn find_all<'g>(&'g self, value: &'g T) -> impl Iterator<Item = Point2<usize>> + 'g
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.rows().flatten().filter(|v| *v == value).count()
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.rows().flatten().map(&mut f).collect())
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| self[(p.x, p.y)].clone())
+    }
+
+    /// Turn the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[(self.height - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Turn the grid a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[(p.x, self.width - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> Grid<T, S> {
+    pub fn get_mut(&mut self, p: Point2<usize>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+}
+
+impl<T, S: Deref<Target = [T]>> Index<Point2<usize>> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, p: Point2<usize>) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{p} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> IndexMut<Point2<usize>> for Grid<T, S> {
+    fn index_mut(&mut self, p: Point2<usize>) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{p} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+/// Index by `(row, col)`.
+impl<T, S: Deref<Target = [T]>> Index<(usize, usize)> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self[Point2::new(col, row)]
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> IndexMut<(usize, usize)> for Grid<T, S> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self[Point2::new(col, row)]
+    }
+}
+
+/// How a cell is drawn when a grid is displayed.
+pub trait Cell {
+    fn to_char(&self) -> char;
+}
+
+impl Cell for u8 {
+    fn to_char(&self) -> char {
+        *self as char
+    }
+}
+
+impl Cell for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl Cell for bool {
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+impl<T: Cell, S: Deref<Target = [T]>> Display for Grid<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(Cell::to_char).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid for worlds without edges: only the cells that have something in them are stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<Point2<isize>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build from the lines of the input, keeping the cells `f` returns something for. The top
+    /// left of the input is the origin.
+    pub fn from_bytes<B: AsRef<[u8]> + ?Sized>(
+        input: &B,
+        mut f: impl FnMut(u8) -> Option<T>,
+    ) -> Self {
+        let mut grid = Self::new();
+        for (y, line) in input.as_ref().split(|&b| b == b'\n').enumerate() {
+            for (x, &b) in line.iter().enumerate() {
+                if let Some(v) = f(b) {
+                    grid.insert(Point2::new(x as isize, y as isize), v);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point2<isize>) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Point2<isize>) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point2<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn insert(&mut self, p: Point2<isize>, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point2<isize>) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn retain(&mut self, mut f: impl FnMut(Point2<isize>, &mut T) -> bool) {
+        self.cells.retain(|p, v| f(*p, v));
+    }
+
+    /// The occupied cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<isize>, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2<isize>> {
+        self.cells.keys().copied()
+    }
+
+    /// The occupied orthogonal neighbors of a point.
+    pub fn neighbors4(&self, p: Point2<isize>) -> impl Iterator<Item = Point2<isize>> {
+        p.neighbors4().filter(|n| self.contains(*n))
+    }
+
+    /// The occupied neighbors of a point, including diagonals.
+    pub fn neighbors8(&self, p: Point2<isize>) -> impl Iterator<Item = Point2<isize>> {
+        p.neighbors8().filter(|n| self.contains(*n))
+    }
+
+    /// The smallest and largest corners of a box around every occupied cell.
+    pub fn bounds(&self) -> Option<(Point2<isize>, Point2<isize>)> {
+        self.cells.keys().fold(None, |bounds, p| {
+            let (min, max) = bounds.unwrap_or((*p, *p));
+            Some((
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            ))
+        })
+    }
+}
+
+/// Draws the bounding box of the occupied cells with `.` for the empty ones.
+impl<T: Cell> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            let line: String = (min.x..=max.x)
+                .map(|x| self.get(Point2::new(x, y)).map_or('.', Cell::to_char))
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#..\n.#.\n..S\n.#.\n";
+
+    #[test]
+    fn test_from_bytes() {
+        let grid = Grid::from_bytes(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(2, 2)], b'S');
+        assert_eq!(grid[Point2::new(1, 3)], b'#');
+        assert_eq!(grid.row(1), b".#.");
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.find(&b'S'), Some(Point2::new(2, 2)));
+        assert_eq!(grid.count(&b'#'), 3);
+        assert_eq!(grid.to_string(), INPUT);
+
+        // No trailing newline is the same grid.
+        assert_eq!(Grid::from_bytes(INPUT.trim_end()).unwrap(), grid);
+        assert!(Grid::from_bytes("##\n#\n").is_err());
+        assert!(Grid::from_bytes("\n").is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbors4(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point2::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8(Point2::new(2, 1)).count(), 3);
+        assert_eq!(grid.points().count(), 6);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::from_bytes("ab\ncd\nef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_left().to_string(), "bdf\nace\n");
+        assert_eq!(
+            grid.rotate_right().rotate_right().rotate_right(),
+            grid.rotate_left()
+        );
+
+        let mut grid = grid.map(|&b| b == b'a');
+        grid[(2, 1)] = true;
+        assert_eq!(grid.to_string(), "#.\n..\n.#\n");
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = SparseGrid::from_bytes(INPUT, |b| (b != b'.').then_some(b));
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.neighbors8(Point2::new(1, 1)).count(), 2);
+        grid.insert(Point2::new(-1, 1), b'@');
+        assert_eq!(grid.bounds(), Some((Point2::new(-1, 0), Point2::new(2, 3))));
+        assert_eq!(grid.to_string(), ".#..\n@.#.\n...S\n..#.\n");
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::VecDeque;
+
+use md5::{Digest, Md5};
+use rayon::prelude::*;
+
+/// The raw bytes of an MD5 hash, looked at a hex digit (nibble) at a time so nothing has to be
+/// formatted to check it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Md5Hash([u8; 16]);
+
+impl Md5Hash {
+    pub fn of(data: &[u8]) -> Self {
+        Self(Md5::digest(data).into())
+    }
+
+    pub fn bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// The `i`th hex digit, counting from the left.
+    pub fn nibble(&self, i: usize) -> u8 {
+        match i % 2 {
+            0 => self.0[i / 2] >> 4,
+            _ => self.0[i / 2] & 0x0f,
+        }
+    }
+
+    pub fn nibbles(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..32).map(|i| self.nibble(i))
+    }
+
+    /// How many hex digits at the front are 0.
+    pub fn leading_zeros(&self) -> usize {
+        u128::from_be_bytes(self.0).leading_zeros() as usize / 4
+    }
+
+    /// The hex digit at the start of every `len` of the same digit in a row, left to right. Longer
+    /// runs show up more than once.
+    pub fn runs(&self, len: usize) -> impl Iterator<Item = u8> {
+        let nibbles: [u8; 32] = std::array::from_fn(|i| self.nibble(i));
+        (0..=32 - len)
+            .filter(move |&i| nibbles[i + 1..i + len].iter().all(|&n| n == nibbles[i]))
+            .map(move |i| nibbles[i])
+    }
+
+    /// Lowercase hex, the way it's usually written out.
+    pub fn hex(&self) -> [u8; 32] {
+        let mut hex = [0; 32];
+        for (i, digit) in hex.iter_mut().enumerate() {
+            *digit = b"0123456789abcdef"[self.nibble(i) as usize];
+        }
+        hex
+    }
+}
+
+/// Hashes of a secret followed by a number, like `abc0`, `abc1`, `abc2`, ... The hasher keeps its
+/// state after the secret so only the number gets hashed each time.
+///
+/// Stretching hashes the hex of the hash again that many more times.
+#[derive(Clone)]
+pub struct Md5Search {
+    prefix: Md5,
+    stretch: usize,
+}
+
+impl Md5Search {
+    pub fn new(secret: &str) -> Self {
+        Self {
+            prefix: Md5::new_with_prefix(secret.as_bytes()),
+            stretch: 0,
+        }
+    }
+
+    pub fn stretch(mut self, times: usize) -> Self {
+        self.stretch = times;
+        self
+    }
+
+    /// The secret followed by `suffix`. This isn't stretched.
+    pub fn hash_with(&self, suffix: &[u8]) -> Md5Hash {
+        Md5Hash(self.prefix.clone().chain_update(suffix).finalize().into())
+    }
+
+    /// The (stretched) hash for `index`.
+    pub fn hash(&self, index: usize) -> Md5Hash {
+        let mut buf = [0; 20];
+        let mut hash = self.hash_with(decimal(index, &mut buf));
+        for _ in 0..self.stretch {
+            hash = Md5Hash::of(&hash.hex());
+        }
+        hash
+    }
+
+    /// Every index from `start` on whose hash passes `predicate`, in order. Hashes are worked out
+    /// in parallel a block at a time and the blocks get bigger the longer it goes.
+    pub fn matches<F>(&self, start: usize, predicate: F) -> Matches<'_, F>
+    where
+        F: Fn(&Md5Hash) -> bool + Sync,
+    {
+        Matches {
+            search: self,
+            predicate,
+            next: start,
+            block: 1 << 10,
+            found: VecDeque::new(),
+        }
+    }
+
+    /// A cache of hashes for when the same ones get looked at over and over.
+    pub fn cache(&self) -> HashCache<'_> {
+        HashCache {
+            search: self,
+            hashes: vec![],
+        }
+    }
+}
+
+/// See [`Md5Search::matches`].
+pub struct Matches<'a, F> {
+    search: &'a Md5Search,
+    predicate: F,
+    next: usize,
+    block: usize,
+    found: VecDeque<(usize, Md5Hash)>,
+}
+
+impl<F> Iterator for Matches<'_, F>
+where
+    F: Fn(&Md5Hash) -> bool + Sync,
+{
+    type Item = (usize, Md5Hash);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() {
+            let (search, predicate) = (self.search, &self.predicate);
+            let found = (self.next..self.next + self.block)
+                .into_par_iter()
+                .filter_map(|i| {
+                    let hash = search.hash(i);
+                    predicate(&hash).then_some((i, hash))
+                })
+                .collect::<Vec<_>>();
+            self.found.extend(found);
+            self.next += self.block;
+            self.block = (self.block * 2).min(1 << 16);
+        }
+        self.found.pop_front()
+    }
+}
+
+/// See [`Md5Search::cache`]. Missing hashes are filled in a block at a time in parallel.
+pub struct HashCache<'a> {
+    search: &'a Md5Search,
+    hashes: Vec<Md5Hash>,
+}
+
+impl HashCache<'_> {
+    pub fn get(&mut self, index: usize) -> Md5Hash {
+        if index >= self.hashes.len() {
+            let end = (index + 1).next_multiple_of(1 << 10);
+            let search = self.search;
+            let more = (self.hashes.len()..end)
+                .into_par_iter()
+                .map(|i| search.hash(i))
+                .collect::<Vec<_>>();
+            self.hashes.extend(more);
+        }
+        self.hashes[index]
+    }
+}
+
+// Write `n` out in decimal without allocating.
+fn decimal(mut n: usize, buf: &mut [u8; 20]) -> &[u8] {
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return &buf[i..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal() {
+        let mut buf = [0; 20];
+        for n in [0, 7, 10, 609043, usize::MAX] {
+            assert_eq!(decimal(n, &mut buf), n.to_string().as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        // The examples from 2015 day 4 and 2016 day 14.
+        let hash = Md5Search::new("abcdef").hash(609043);
+        assert_eq!(&hash.hex()[..6], b"000001");
+        assert_eq!(hash.leading_zeros(), 5);
+        assert_eq!(hash.nibble(5), 1);
+
+        let search = Md5Search::new("abc");
+        assert_eq!(search.hash(18).runs(3).next(), Some(8));
+        assert_eq!(search.hash(816).runs(5).next(), Some(0xe));
+        assert_eq!(search.hash(0), Md5Hash::of(b"abc0"));
+        assert_eq!(search.hash_with(b"123"), Md5Hash::of(b"abc123"));
+
+        let stretched = Md5Search::new("abc").stretch(2016).hash(0);
+        assert_eq!(&stretched.hex()[..6], b"a107ff");
+    }
+
+    #[test]
+    fn test_matches() {
+        let search = Md5Search::new("abc");
+        let expected = (0..40000)
+            .filter(|&i| search.hash(i).nibble(0) == 0 && search.hash(i).nibble(1) == 0)
+            .collect::<Vec<_>>();
+        let found = search
+            .matches(0, |hash| hash.bytes()[0] == 0)
+            .map(|(i, _)| i)
+            .take_while(|&i| i < 40000)
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+
+        let mut cache = search.cache();
+        assert_eq!(cache.get(5000), search.hash(5000));
+        assert_eq!(cache.get(3), search.hash(3));
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{LinearSystem, Matrix, Rational};
+
+/// Minimize `cost · x` subject to `a * x = b` and `x >= 0` over the integers. The cost defaults to
+/// the sum of `x`.
+///
+/// It's branch and bound on the linear programming relaxation, which is solved exactly with the
+/// simplex method, so the answer is provably optimal. Meant for small problems where the
+/// solutions are bounded.
+///
+/// <https://en.wikipedia.org/wiki/Branch_and_bound>
+#[derive(Debug, Clone)]
+pub struct IntegerProgram<'a> {
+    a: &'a Matrix,
+    b: &'a [i128],
+    cost: Vec<i128>,
+}
+
+/// The best solution and what it costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimum {
+    pub x: Vec<i128>,
+    pub cost: i128,
+}
+
+// What the relaxation came back with.
+enum Lp {
+    Optimal(Vec<Rational>),
+    Infeasible,
+    Unbounded,
+}
+
+impl<'a> IntegerProgram<'a> {
+    pub fn new(a: &'a Matrix, b: &'a [i128]) -> Self {
+        assert_eq!(a.rows(), b.len(), "b doesn't match the rows of a");
+        Self {
+            a,
+            b,
+            cost: vec![1; a.cols()],
+        }
+    }
+
+    /// The cost of one of each variable.
+    pub fn cost(self, cost: Vec<i128>) -> Self {
+        assert_eq!(cost.len(), self.a.cols(), "need a cost for each variable");
+        Self { cost, ..self }
+    }
+
+    /// The cheapest solution, or `None` if there aren't any or the cost has no minimum.
+    pub fn solve(&self) -> Option<Optimum> {
+        // Branching never ends when the relaxation has room but no integer points, so rule that
+        // out first.
+        if !LinearSystem::new(self.a, self.b).has_integer_solution() {
+            return None;
+        }
+        let n = self.a.cols();
+        let limits = self.limits();
+        let mut best: Option<Optimum> = None;
+
+        // Each node is a lower and (maybe) upper bound for each variable.
+        let mut stack = vec![(vec![0; n], vec![None; n])];
+        while let Some((lo, hi)) = stack.pop() {
+            let x = match self.relax(&lo, &hi) {
+                Lp::Optimal(x) => x,
+                Lp::Infeasible => continue,
+                Lp::Unbounded => return None,
+            };
+
+            // The costs are integers, so nothing under here can do better than rounding up.
+            let bound = self
+                .cost
+                .iter()
+                .zip(&x)
+                .fold(Rational::ZERO, |acc, (&c, &v)| acc + Rational::from(c) * v)
+                .ceil();
+            if best.as_ref().is_some_and(|best| bound >= best.cost) {
+                continue;
+            }
+
+            // Split on a variable that isn't a whole number, or take it if they all are.
+            match x.iter().position(|v| !v.is_integer()) {
+                None => {
+                    best = Some(Optimum {
+                        x: x.iter().map(|v| v.numer()).collect(),
+                        cost: bound,
+                    })
+                }
+                Some(i) => {
+                    let split = x[i].floor();
+                    if limits[i].is_none_or(|limit| split < limit) {
+                        let mut up = lo.clone();
+                        up[i] = split + 1;
+                        stack.push((up, hi.clone()));
+                    }
+                    let mut down = hi;
+                    down[i] = Some(split);
+                    stack.push((lo, down));
+                }
+            }
+        }
+        best
+    }
+
+    // The most each variable can be. A row without negative coefficients caps every variable in
+    // it, since nothing else in the row can make up for it.
+    fn limits(&self) -> Vec<Option<i128>> {
+        let rows = (0..self.a.rows())
+            .filter(|&r| self.a.row(r).iter().all(|&v| v >= 0))
+            .collect::<Vec<_>>();
+        (0..self.a.cols())
+            .map(|i| {
+                rows.iter()
+                    .filter(|&&r| self.a[(r, i)] > 0)
+                    .map(|&r| self.b[r].div_euclid(self.a[(r, i)]))
+                    .min()
+            })
+            .collect()
+    }
+
+    // Solve the problem over the rationals with the given bounds. Shifting by the lower bounds
+    // makes every variable start at zero, and each upper bound gets a row with a slack variable.
+    fn relax(&self, lo: &[i128], hi: &[Option<i128>]) -> Lp {
+        let n = self.a.cols();
+        let uppers = hi
+            .iter()
+            .enumerate()
+            .filter_map(|(i, h)| h.map(|h| (i, h - lo[i])))
+            .collect::<Vec<_>>();
+        if uppers.iter().any(|&(_, u)| u < 0) {
+            return Lp::Infeasible;
+        }
+
+        let vars = n + uppers.len();
+        let mut rows = vec![];
+        let mut rhs = vec![];
+        for (r, &b) in self.b.iter().enumerate() {
+            let row = self.a.row(r);
+            let mut equation = vec![Rational::ZERO; vars];
+            for (e, &v) in equation.iter_mut().zip(row) {
+                *e = v.into();
+            }
+            rows.push(equation);
+            rhs.push((b - row.iter().zip(lo).map(|(a, l)| a * l).sum::<i128>()).into());
+        }
+        for (k, &(i, u)) in uppers.iter().enumerate() {
+            let mut equation = vec![Rational::ZERO; vars];
+            equation[i] = Rational::ONE;
+            equation[n + k] = Rational::ONE;
+            rows.push(equation);
+            rhs.push(u.into());
+        }
+
+        let mut cost = vec![Rational::ZERO; vars];
+        for (c, &v) in cost.iter_mut().zip(&self.cost) {
+            *c = v.into();
+        }
+
+        match minimize(rows, rhs, &cost) {
+            Lp::Optimal(y) => Lp::Optimal(
+                y.iter()
+                    .zip(lo)
+                    .map(|(&y, &l)| y + Rational::from(l))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+// Minimize `cost · x` subject to `rows * x = rhs` and `x >= 0` with the two phase simplex method.
+//
+// https://en.wikipedia.org/wiki/Simplex_algorithm
+fn minimize(rows: Vec<Vec<Rational>>, rhs: Vec<Rational>, cost: &[Rational]) -> Lp {
+    let (m, n) = (rows.len(), cost.len());
+
+    // The tableau has the variables, then an artificial variable for each row, then the right
+    // hand side. Flipping rows so the right hand side isn't negative lets the artificials start
+    // out as the basis.
+    let mut tableau = rows
+        .into_iter()
+        .zip(rhs)
+        .enumerate()
+        .map(|(r, (mut row, b))| {
+            let flip = b < Rational::ZERO;
+            row.extend((0..m).map(|a| match a == r {
+                true => Rational::ONE,
+                false => Rational::ZERO,
+            }));
+            row.push(b);
+            if flip {
+                row.iter_mut().take(n).for_each(|v| *v = -*v);
+                row[n + m] = -row[n + m];
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+    let mut basis = (n..n + m).collect::<Vec<_>>();
+
+    // Phase one drives the artificials to zero if it can. If it can't there's no solution.
+    let artificial = (0..n + m)
+        .map(|c| match c < n {
+            true => Rational::ZERO,
+            false => Rational::ONE,
+        })
+        .collect::<Vec<_>>();
+    if !optimize(&mut tableau, &mut basis, &artificial, n + m)
+        || objective(&tableau, &basis, &artificial) > Rational::ZERO
+    {
+        return Lp::Infeasible;
+    }
+
+    // Swap out any artificials left in the basis. If a row has nothing else it was redundant.
+    for r in 0..m {
+        if basis[r] >= n
+            && let Some(c) = (0..n).find(|&c| !tableau[r][c].is_zero())
+        {
+            pivot(&mut tableau, &mut basis, r, c);
+        }
+    }
+
+    // Phase two minimizes the real cost without letting the artificials back in.
+    let mut cost = cost.to_vec();
+    cost.resize(n + m, Rational::ZERO);
+    if !optimize(&mut tableau, &mut basis, &cost, n) {
+        return Lp::Unbounded;
+    }
+
+    let mut x = vec![Rational::ZERO; n];
+    for (r, &c) in basis.iter().enumerate() {
+        if c < n {
+            x[c] = tableau[r][n + m];
+        }
+    }
+    Lp::Optimal(x)
+}
+
+fn objective(tableau: &[Vec<Rational>], basis: &[usize], cost: &[Rational]) -> Rational {
+    basis
+        .iter()
+        .zip(tableau)
+        .fold(Rational::ZERO, |acc, (&c, row)| {
+            acc + cost[c] * row[row.len() - 1]
+        })
+}
+
+// Pivot until no column below `allowed` can lower the cost. Returns false if the cost is
+// unbounded. Bland's rule (the first column that helps and the first row that limits it) keeps
+// it from cycling.
+fn optimize(
+    tableau: &mut [Vec<Rational>],
+    basis: &mut [usize],
+    cost: &[Rational],
+    allowed: usize,
+) -> bool {
+    loop {
+        let reduced = |c: usize| {
+            basis
+                .iter()
+                .zip(tableau.iter())
+                .fold(cost[c], |acc, (&b, row)| acc - cost[b] * row[c])
+        };
+        let Some(col) = (0..allowed).find(|&c| !basis.contains(&c) && reduced(c) < Rational::ZERO)
+        else {
+            return true;
+        };
+
+        let Some(row) = (0..tableau.len())
+            .filter(|&r| tableau[r][col] > Rational::ZERO)
+            .min_by(|&a, &b| {
+                let ratio = |r: usize| tableau[r][tableau[r].len() - 1] / tableau[r][col];
+                ratio(a).cmp(&ratio(b)).then(basis[a].cmp(&basis[b]))
+            })
+        else {
+            return false;
+        };
+        pivot(tableau, basis, row, col);
+    }
+}
+
+fn pivot(tableau: &mut [Vec<Rational>], basis: &mut [usize], row: usize, col: usize) {
+    let p = tableau[row][col];
+    tableau[row].iter_mut().for_each(|v| *v = *v / p);
+    let pivot_row = tableau[row].clone();
+    for (r, other) in tableau.iter_mut().enumerate() {
+        let factor = other[col];
+        if r != row && !factor.is_zero() {
+            other
+                .iter_mut()
+                .zip(&pivot_row)
+                .for_each(|(v, &p)| *v = *v - factor * p);
+        }
+    }
+    basis[row] = col;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The fewest presses of buttons that each add one to some counters to reach the joltages.
+    fn presses(buttons: &[&[usize]], joltages: &[i128]) -> i128 {
+        let a = Matrix::from_fn(joltages.len(), buttons.len(), |r, c| {
+            buttons[c].contains(&r) as i128
+        });
+        let optimum = IntegerProgram::new(&a, joltages).solve().unwrap();
+        assert!(optimum.x.iter().all(|&x| x >= 0));
+        for (r, &j) in joltages.iter().enumerate() {
+            let total = buttons
+                .iter()
+                .zip(&optimum.x)
+                .filter(|(b, _)| b.contains(&r))
+                .map(|(_, x)| x)
+                .sum::<i128>();
+            assert_eq!(total, j);
+        }
+        assert_eq!(optimum.x.iter().sum::<i128>(), optimum.cost);
+        optimum.cost
+    }
+
+    #[test]
+    fn test_presses() {
+        // The 2025 day 10 sample.
+        let buttons: &[&[usize]] = &[&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        assert_eq!(presses(buttons, &[3, 5, 4, 7]), 10);
+        let buttons: &[&[usize]] = &[&[0, 2, 3, 4], &[2, 3], &[0, 4], &[0, 1, 2], &[1, 2, 3, 4]];
+        assert_eq!(presses(buttons, &[7, 5, 12, 7, 2]), 12);
+        let buttons: &[&[usize]] = &[&[0, 1, 2, 3, 4], &[0, 3, 4], &[0, 1, 2, 4, 5], &[1, 2]];
+        assert_eq!(presses(buttons, &[10, 11, 11, 5, 10, 5]), 11);
+    }
+
+    #[test]
+    fn test_branching() {
+        // 2x + 2y + z = 7 with x and y cheap. The relaxation wants x = 3.5.
+        let a = Matrix::from([[2, 2, 1]]);
+        let optimum = IntegerProgram::new(&a, &[7]).cost(vec![1, 1, 5]).solve();
+        assert_eq!(optimum.map(|o| o.cost), Some(8));
+
+        // Brute force a small knapsack-like problem with a slack column.
+        let a = Matrix::from([[3, 5, 7, 1]]);
+        let cost = vec![-4, -7, -10, 0];
+        let optimum = IntegerProgram::new(&a, &[20]).cost(cost.clone()).solve();
+        let mut expected = i128::MAX;
+        for x in 0..=6 {
+            for y in 0..=4 {
+                for z in 0..=2 {
+                    let used = 3 * x + 5 * y + 7 * z;
+                    if used <= 20 {
+                        expected = expected.min(-4 * x - 7 * y - 10 * z);
+                    }
+                }
+            }
+        }
+        assert_eq!(optimum.map(|o| o.cost), Some(expected));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // Only even numbers add up from 2 and 4.
+        let a = Matrix::from([[2, 4]]);
+        assert_eq!(IntegerProgram::new(&a, &[7]).solve(), None);
+
+        // x - y = 1 lets x grow forever, and the cost goes down with it.
+        let a = Matrix::from([[1, -1]]);
+        let unbounded = IntegerProgram::new(&a, &[1]).cost(vec![-1, 0]).solve();
+        assert_eq!(unbounded, None);
+
+        // Contradicting rows.
+        let a = Matrix::from([[1, 1], [1, 1]]);
+        assert_eq!(IntegerProgram::new(&a, &[1, 2]).solve(), None);
+
+        // 2x - 2y = 1 has room to branch forever but no integer points at all.
+        let a = Matrix::from([[2, -2]]);
+        assert_eq!(IntegerProgram::new(&a, &[1]).solve(), None);
+    }
+}
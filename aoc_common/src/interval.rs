@@ -0,0 +1,300 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::Coord;
+
+/// The integers an `IntervalSet` can hold.
+pub trait Integer: Coord + Ord {
+    /// How many values there are from `start` to `end`. Only the full range of a `u128` or `i128`
+    /// doesn't fit, so that saturates.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! integer {
+    ($(($t:ty, $u:ty)),*) => {
+        $(
+            impl Integer for $t {
+                fn count(start: Self, end: Self) -> u128 {
+                    ((end as $u).wrapping_sub(start as $u) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+integer!(
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+    (isize, usize),
+    (u8, u8),
+    (u16, u16),
+    (u32, u32),
+    (u64, u64),
+    (u128, u128),
+    (usize, usize)
+);
+
+/// A set of integers stored as sorted, disjoint ranges. Ranges that overlap or touch are merged
+/// as they're added, and nothing overflows at the ends of the type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // start -> end, inclusive. No two of them overlap or touch.
+    ranges: BTreeMap<T, T>,
+}
+
+// Whether a range ending at `end` overlaps or touches one beginning at `start`.
+fn touches<T: Integer>(end: T, start: T) -> bool {
+    end.checked_add(T::ONE).is_none_or(|next| next >= start)
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|(&s, &e)| T::count(s, e)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges in order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&s, &e)| s..=e)
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Pick up the range before this one if it reaches us and any after that we reach.
+        if let Some((&s, &e)) = self.ranges.range(..=start).next_back()
+            && touches(e, start)
+        {
+            self.ranges.remove(&s);
+            (start, end) = (s, end.max(e));
+        }
+        while let Some((&s, &e)) = self.ranges.range(start..).next()
+            && touches(end, s)
+        {
+            self.ranges.remove(&s);
+            end = end.max(e);
+        }
+        self.ranges.insert(start, end);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // The ranges are disjoint so their ends are in order too.
+        let overlapping = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|&(_, &e)| e >= start)
+            .map(|(&s, &e)| (s, e))
+            .collect::<Vec<_>>();
+        for (s, e) in overlapping {
+            self.ranges.remove(&s);
+            if s < start {
+                self.ranges.insert(s, start - T::ONE);
+            }
+            if e > end {
+                self.ranges.insert(end + T::ONE, e);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &e)| e >= value)
+    }
+
+    /// Whether every value in the range is in the set.
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        start > end
+            || self
+                .ranges
+                .range(..=start)
+                .next_back()
+                .is_some_and(|(_, &e)| e >= end)
+    }
+
+    /// The ranges within `bounds` that aren't in the set, in order.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let (lo, hi) = bounds.into_inner();
+
+        // Start from the range covering lo, if there is one.
+        let first = match self.ranges.range(..=lo).next_back() {
+            Some((&s, &e)) if e >= lo => s,
+            _ => lo,
+        };
+        let mut ranges = self.ranges.range(first..=hi.max(first));
+
+        // The first value that could be in the next gap, or None once we're past hi.
+        let mut next = (lo <= hi).then_some(lo);
+        std::iter::from_fn(move || {
+            loop {
+                let cur = next?;
+                match ranges.next() {
+                    Some((&s, &e)) => {
+                        next = e.checked_add(T::ONE).filter(|&n| n <= hi);
+                        if s > cur {
+                            return Some(cur..=s - T::ONE);
+                        }
+                    }
+                    None => {
+                        next = None;
+                        return Some(cur..=hi);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Everything within `bounds` that isn't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        self.gaps(bounds).collect()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // Walk both in order, always moving past whichever range ends first.
+        let mut intersection = Self::new();
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = *x.start().max(y.start());
+            let end = *x.end().min(y.end());
+            if start <= end {
+                intersection.ranges.insert(start, end);
+            }
+            match x.end() < y.end() {
+                true => a.next(),
+                false => b.next(),
+            };
+        }
+        intersection
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges<T: Integer>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set: IntervalSet<i32> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(ranges(&set), [3..=5, 10..=20]);
+        assert_eq!(set.len(), 14);
+
+        // Touching ranges merge, empty ones are ignored.
+        set.insert(6..=9);
+        let (start, end) = (30, 25);
+        set.insert(start..=end);
+        assert_eq!(ranges(&set), [3..=20]);
+        set.insert(-4..=-2);
+        set.insert(0..=100);
+        assert_eq!(ranges(&set), [-4..=-2, 0..=100]);
+
+        assert!(set.contains(-3) && set.contains(100));
+        assert!(!set.contains(-1) && !set.contains(101) && !set.contains(-5));
+        assert!(set.contains_range(5..=60));
+        assert!(!set.contains_range(-3..=1));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: IntervalSet<u32> = [0..=10, 20..=30, 40..=50].into_iter().collect();
+        set.remove(5..=24);
+        assert_eq!(ranges(&set), [0..=4, 25..=30, 40..=50]);
+        set.remove(26..=29);
+        set.remove(40..=40);
+        set.remove(50..=60);
+        assert_eq!(ranges(&set), [0..=4, 25..=25, 30..=30, 41..=49]);
+        set.remove(0..=u32::MAX);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_ops() {
+        let a: IntervalSet<i64> = [0..=10, 20..=30].into_iter().collect();
+        let b: IntervalSet<i64> = [5..=25, 28..=40].into_iter().collect();
+        assert_eq!(ranges(&a.union(&b)), [0..=40]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25, 28..=30]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=27]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19, 31..=40]);
+        assert_eq!(ranges(&a.complement(-5..=25)), [-5..=-1, 11..=19]);
+        assert_eq!(ranges(&a.complement(12..=18)), [12..=18]);
+        assert!(a.complement(2..=8).is_empty());
+    }
+
+    #[test]
+    fn test_limits() {
+        // Nothing should overflow at the ends of the type.
+        let mut set: IntervalSet<u8> = [0..=3, 250..=255].into_iter().collect();
+        assert_eq!(ranges(&set.complement(0..=255)), [4..=249]);
+        assert_eq!(set.gaps(0..=255).next(), Some(4..=249));
+        assert!(set.contains(255));
+        set.insert(4..=249);
+        assert_eq!(ranges(&set), [0..=255]);
+        assert_eq!(set.len(), 256);
+        assert_eq!(set.gaps(0..=255).count(), 0);
+
+        let set: IntervalSet<i8> = [-128..=-100, 100..=127].into_iter().collect();
+        assert_eq!(ranges(&set.complement(-128..=127)), [-99..=99]);
+
+        let mut set = IntervalSet::new();
+        set.insert(0..=u32::MAX);
+        assert_eq!(set.len(), 1 << 32);
+        set.remove(u32::MAX..=u32::MAX);
+        assert_eq!(ranges(&set.complement(0..=u32::MAX)), [u32::MAX..=u32::MAX]);
+    }
+}
@@ -0,0 +1,217 @@
+//! Shared pieces used by every year crate and the `aoc` runner.
+
+mod answer;
+mod answers;
+mod automaton;
+mod bitset;
+mod compress;
+mod cycle;
+mod disjoint;
+mod grid;
+mod hashing;
+mod ilp;
+mod interval;
+mod linalg;
+mod number;
+mod point;
+mod polyomino;
+mod rational;
+mod search;
+mod solution;
+#[cfg(test)]
+mod testing;
+mod tsp;
+
+pub use answer::Answer;
+pub use answers::{Answers, Status, input_hash};
+pub use automaton::{Automaton, Rule};
+pub use bitset::BitSet;
+pub use compress::{Axis, CompressedPlane, PrefixSum2, Region};
+pub use cycle::{Cycle, brent, find_cycle, floyd, nth};
+pub use disjoint::{DisjointSet, KeyedDisjointSet};
+pub use grid::{ByteGrid, Cell, Grid, SparseGrid};
+pub use hashing::{HashCache, Matches, Md5Hash, Md5Search};
+pub use ilp::{IntegerProgram, Optimum};
+pub use interval::{Integer, IntervalSet};
+pub use linalg::{LinearSystem, Matrix};
+pub use number::{
+    ModArith, crt, discrete_log, divisor_sums, divisor_sums_within, extended_gcd, mod_inverse,
+};
+pub use point::{Coord, Point2, Point3};
+pub use polyomino::{Packing, Placement, Polyomino};
+pub use rational::Rational;
+pub use search::{
+    ShortestPath, StateSpace, Stats, WeightedStateSpace, astar, bfs, bidirectional_bfs, dijkstra,
+    reachable,
+};
+pub use solution::Solution;
+pub use tsp::{HeldKarp, Tour};
+
+use std::{
+    error::Error,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// A single timed computation for a day. Most days have a "p1" and "p2", but some keep
+/// alternative implementations around (e.g. "p2_z3") that report under the same part.
+pub struct Part<S: Solution> {
+    pub part: u8,
+    pub name: &'static str,
+    solve: for<'a> fn(&S::Input<'a>) -> Result<Answer>,
+}
+
+/// The answer from running a part along with how long it took.
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timed {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl<S: Solution> Part<S> {
+    /// The "p1" entry for a solution.
+    pub const fn part1() -> Self {
+        Self::variant(1, "p1", S::part1)
+    }
+
+    /// The "p2" entry for a solution.
+    pub const fn part2() -> Self {
+        Self::variant(2, "p2", S::part2)
+    }
+
+    /// Another implementation of a part, which gets the same parsed input.
+    pub const fn variant(
+        part: u8,
+        name: &'static str,
+        solve: for<'a> fn(&S::Input<'a>) -> Result<Answer>,
+    ) -> Self {
+        Self { part, name, solve }
+    }
+}
+
+/// A part without the types of the solution it came from, so every day can go in one list.
+pub trait Run: Sync {
+    fn part(&self) -> u8;
+
+    fn name(&self) -> &'static str;
+
+    fn run(&self, input: &str) -> Result<Timed>;
+}
+
+// The parts of a day, whatever solution they're for.
+trait Parts: Sync {
+    fn get(&self, i: usize) -> Option<&dyn Run>;
+}
+
+impl<S: Solution, const N: usize> Parts for [Part<S>; N] {
+    fn get(&self, i: usize) -> Option<&dyn Run> {
+        self.as_slice().get(i).map(|part| part as &dyn Run)
+    }
+}
+
+/// A day's entry in a year's registry.
+pub struct Day {
+    pub day: u8,
+    parts: &'static dyn Parts,
+}
+
+impl Day {
+    pub const fn new<S: Solution, const N: usize>(day: u8, parts: &'static [Part<S>; N]) -> Self {
+        Self { day, parts }
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = &dyn Run> {
+        (0..).map_while(|i| self.parts.get(i))
+    }
+
+    /// Run every part against the input and make sure all of the variants of a part agree.
+    pub fn check_variants(&self, input: &str) -> Result<()> {
+        let mut answers: Vec<(&dyn Run, Answer)> = vec![];
+        for part in self.parts() {
+            let answer = part
+                .run(input)
+                .map_err(|err| format!("{}: {err}", part.name()))?
+                .answer;
+            if let Some((other, expected)) = answers.iter().find(|(p, _)| p.part() == part.part())
+                && *expected != answer
+            {
+                return Err(format!(
+                    "{} gave {answer} but {} gave {expected}",
+                    part.name(),
+                    other.name()
+                )
+                .into());
+            }
+            answers.push((part, answer));
+        }
+        Ok(())
+    }
+}
+
+/// All of the registered days for a year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: u16, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+
+    pub fn day(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// The root of the workspace the runner was built from.
+pub fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The default location of a day's input: `aoc_<year>/inputs/dayNN.txt` in the workspace.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    workspace()
+        .join(format!("aoc_{year}"))
+        .join("inputs")
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Where the known answers live. Like the inputs, they aren't committed.
+pub fn answers_path() -> PathBuf {
+    workspace().join("answers.txt")
+}
+
+/// Read the input for a day. An explicit path overrides the default location and "-" reads from
+/// stdin.
+pub fn load_input(year: u16, day: u8, path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|err| format!("unable to read input '{}': {err}", path.display()).into()),
+        None => {
+            let path = input_path(year, day);
+            std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => format!(
+                    "no input for {year} day {day}: expected '{}' (run `cargo aoc get {year} {day}` or pass --input)",
+                    path.display()
+                )
+                .into(),
+                _ => format!("unable to read input '{}': {err}", path.display()).into(),
+            })
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use std::ops::{Index, IndexMut, RangeInclusive};
+
+use crate::extended_gcd;
+
+fn gcd(a: i128, b: i128) -> i128 {
+    extended_gcd(a, b).0
+}
+
+/// A dense matrix of integers indexed by `(row, col)`.
+///
+/// Elimination is fraction-free: instead of dividing rows by their pivot, rows are cross
+/// multiplied and then divided by the gcd of their entries, so everything stays an exact (and
+/// small) integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<i128>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0; rows * cols],
+        }
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> i128) -> Self {
+        let data = (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .map(|(r, c)| f(r, c))
+            .collect();
+        Self { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, r: usize) -> &[i128] {
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    fn row_mut(&mut self, r: usize) -> &mut [i128] {
+        &mut self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.cols {
+            self.data.swap(a * self.cols + c, b * self.cols + c);
+        }
+    }
+
+    // Divide the row by the gcd of its entries.
+    fn reduce_row(&mut self, r: usize) {
+        let g = self.row(r).iter().fold(0, |g, &v| gcd(g, v));
+        if g > 1 {
+            self.row_mut(r).iter_mut().for_each(|v| *v /= g);
+        }
+    }
+
+    /// Put the matrix in reduced row echelon form and return the pivot column of each non-zero
+    /// row. Pivots are positive but not necessarily 1: a pivot row means
+    /// `pivot * x[col] + (the free columns) = 0`.
+    ///
+    /// <https://en.wikipedia.org/wiki/Row_echelon_form>
+    pub fn rref(&mut self) -> Vec<usize> {
+        let mut pivots = vec![];
+        for col in 0..self.cols {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+
+            // The smallest pivot keeps the numbers down. A column without one is free.
+            let Some(best) = (row..self.rows)
+                .filter(|&r| self[(r, col)] != 0)
+                .min_by_key(|&r| self[(r, col)].unsigned_abs())
+            else {
+                continue;
+            };
+            self.swap_rows(row, best);
+            if self[(row, col)] < 0 {
+                self.row_mut(row).iter_mut().for_each(|v| *v = -*v);
+            }
+            self.reduce_row(row);
+
+            // Clear the column out of every other row.
+            let pivot_row = self.row(row).to_vec();
+            let pivot = pivot_row[col];
+            for r in (0..self.rows).filter(|&r| r != row) {
+                let factor = self[(r, col)];
+                if factor == 0 {
+                    continue;
+                }
+                let g = gcd(pivot, factor);
+                let (a, b) = (pivot / g, factor / g);
+                self.row_mut(r)
+                    .iter_mut()
+                    .zip(&pivot_row)
+                    .for_each(|(v, &p)| *v = *v * a - p * b);
+                self.reduce_row(r);
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    /// The rank of the matrix.
+    pub fn rank(&self) -> usize {
+        self.clone().rref().len()
+    }
+
+    /// An integer basis for the vectors `x` where `self * x = 0`, one for each free column.
+    pub fn nullspace(&self) -> Vec<Vec<i128>> {
+        let mut rref = self.clone();
+        let pivots = rref.rref();
+        nullspace(&rref, &pivots, self.cols)
+    }
+}
+
+/// One array per row.
+impl<const R: usize, const C: usize> From<[[i128; C]; R]> for Matrix {
+    fn from(rows: [[i128; C]; R]) -> Self {
+        Self::from_fn(R, C, |r, c| rows[r][c])
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = i128;
+
+    fn index(&self, (r, c): (usize, usize)) -> &i128 {
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut i128 {
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+// The columns below `vars` that aren't pivots.
+fn free_columns(pivots: &[usize], vars: usize) -> Vec<usize> {
+    (0..vars).filter(|c| !pivots.contains(c)).collect()
+}
+
+// Set each free column to one (scaled up so the pivots divide evenly) and solve for the pivots.
+fn nullspace(rref: &Matrix, pivots: &[usize], vars: usize) -> Vec<Vec<i128>> {
+    let lcm = pivots
+        .iter()
+        .enumerate()
+        .map(|(r, &c)| rref[(r, c)])
+        .fold(1, |l, p| l / gcd(l, p) * p);
+
+    free_columns(pivots, vars)
+        .into_iter()
+        .map(|free| {
+            let mut v = vec![0; vars];
+            v[free] = lcm;
+            for (r, &c) in pivots.iter().enumerate() {
+                v[c] = -rref[(r, free)] * (lcm / rref[(r, c)]);
+            }
+            let g = v.iter().fold(0, |g, &x| gcd(g, x));
+            v.iter_mut().for_each(|x| *x /= g);
+            v
+        })
+        .collect()
+}
+
+/// The linear system `a * x = b` reduced so the pivot variables can be read off from the free
+/// ones. Only integer solutions are considered.
+#[derive(Debug, Clone)]
+pub struct LinearSystem {
+    // The augmented matrix in reduced row echelon form.
+    rref: Matrix,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    consistent: bool,
+}
+
+impl LinearSystem {
+    pub fn new(a: &Matrix, b: &[i128]) -> Self {
+        assert_eq!(a.rows, b.len(), "b doesn't match the rows of a");
+        let vars = a.cols;
+        let mut rref = Matrix::from_fn(a.rows, vars + 1, |r, c| match c == vars {
+            true => b[r],
+            false => a[(r, c)],
+        });
+
+        // A pivot in b means a row of 0 = b.
+        let mut pivots = rref.rref();
+        let consistent = pivots.last() != Some(&vars);
+        if !consistent {
+            pivots.pop();
+        }
+        let free = free_columns(&pivots, vars);
+
+        Self {
+            rref,
+            pivots,
+            free,
+            consistent,
+        }
+    }
+
+    /// Whether there are any solutions at all, ignoring whether they're integers.
+    pub fn is_consistent(&self) -> bool {
+        self.consistent
+    }
+
+    /// Whether any of the solutions are all integers. Swapping columns and adding a multiple of
+    /// one column to another doesn't change that, and they can make each row have a single new
+    /// variable, which either divides evenly or doesn't.
+    ///
+    /// <https://en.wikipedia.org/wiki/Hermite_normal_form>
+    pub fn has_integer_solution(&self) -> bool {
+        if !self.consistent {
+            return false;
+        }
+        let vars = self.vars();
+        let mut rows = (0..self.pivots.len())
+            .map(|r| self.rref.row(r).to_vec())
+            .collect::<Vec<_>>();
+
+        // The values of the variables taken so far, which are the columns before `y.len()`.
+        let mut y = vec![];
+        for r in 0..rows.len() {
+            let col = y.len();
+
+            // Euclid's algorithm across the row until only `col` is left.
+            while let Some(min) = (col..vars)
+                .filter(|&c| rows[r][c] != 0)
+                .min_by_key(|&c| rows[r][c].unsigned_abs())
+            {
+                rows.iter_mut().for_each(|row| row.swap(col, min));
+                for c in col + 1..vars {
+                    let q = rows[r][c] / rows[r][col];
+                    rows.iter_mut().for_each(|row| row[c] -= q * row[col]);
+                }
+                if rows[r][col + 1..vars].iter().all(|&v| v == 0) {
+                    break;
+                }
+            }
+
+            let rest = rows[r][..col]
+                .iter()
+                .zip(&y)
+                .map(|(a, y)| a * y)
+                .sum::<i128>();
+            let n = rows[r][vars] - rest;
+            let p = match col < vars {
+                true => rows[r][col],
+                false => 0,
+            };
+            match p {
+                0 if n != 0 => return false,
+                0 => {}
+                _ if n % p != 0 => return false,
+                _ => y.push(n / p),
+            }
+        }
+        true
+    }
+
+    /// The number of variables.
+    pub fn vars(&self) -> usize {
+        self.rref.cols - 1
+    }
+
+    /// The variables that are determined by the free ones.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// The variables that can take any value.
+    pub fn free(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// The value of each pivot variable, in the order of `pivots`, given a value for each free
+    /// variable in the order of `free`. `None` where it doesn't come out as an integer.
+    pub fn pivot_values<'a>(
+        &'a self,
+        free_values: &'a [i128],
+    ) -> impl Iterator<Item = Option<i128>> + 'a {
+        let vars = self.vars();
+        self.pivots.iter().enumerate().map(move |(r, &c)| {
+            let row = self.rref.row(r);
+            let rest = self
+                .free
+                .iter()
+                .zip(free_values)
+                .map(|(&f, &v)| row[f] * v)
+                .sum::<i128>();
+            let n = row[vars] - rest;
+            (n % row[c] == 0).then(|| n / row[c])
+        })
+    }
+
+    /// Every variable given a value for each free one, if the system is consistent and the
+    /// pivots all come out as integers.
+    pub fn solve(&self, free_values: &[i128]) -> Option<Vec<i128>> {
+        if !self.consistent {
+            return None;
+        }
+        let mut x = vec![0; self.vars()];
+        for (&f, &v) in self.free.iter().zip(free_values) {
+            x[f] = v;
+        }
+        for (&c, v) in self.pivots.iter().zip(self.pivot_values(free_values)) {
+            x[c] = v?;
+        }
+        Some(x)
+    }
+
+    /// Every integer solution with each free variable within its bounds, in the order of `free`.
+    pub fn solutions(
+        &self,
+        bounds: impl IntoIterator<Item = RangeInclusive<i128>>,
+    ) -> impl Iterator<Item = Vec<i128>> + '_ {
+        let bounds = bounds.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            bounds.len(),
+            self.free.len(),
+            "need bounds for each free variable"
+        );
+
+        // Walk the free values like an odometer, with the first one turning fastest.
+        let mut next = (self.consistent && bounds.iter().all(|b| !b.is_empty()))
+            .then(|| bounds.iter().map(|b| *b.start()).collect::<Vec<_>>());
+        std::iter::from_fn(move || {
+            while let Some(values) = next.take() {
+                if let Some(i) = bounds.iter().zip(&values).position(|(b, &v)| v < *b.end()) {
+                    let mut following = values.clone();
+                    for (v, b) in following[..i].iter_mut().zip(&bounds) {
+                        *v = *b.start();
+                    }
+                    following[i] += 1;
+                    next = Some(following);
+                }
+                if let Some(x) = self.solve(&values) {
+                    return Some(x);
+                }
+            }
+            None
+        })
+    }
+
+    /// An integer basis for the directions the solutions can move in, one for each free variable.
+    pub fn nullspace(&self) -> Vec<Vec<i128>> {
+        nullspace(&self.rref, &self.pivots, self.vars())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multiply(a: &Matrix, x: &[i128]) -> Vec<i128> {
+        (0..a.rows())
+            .map(|r| a.row(r).iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect()
+    }
+
+    #[test]
+    fn test_rref() {
+        let mut m = Matrix::from([[2, 4, -2], [4, 9, -3], [-2, -3, 7]]);
+        assert_eq!(m.rank(), 3);
+        assert_eq!(m.rref(), [0, 1, 2]);
+        assert_eq!(m, Matrix::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]));
+
+        // Fractions stay as integers with the pivot out front.
+        let mut m = Matrix::from([[2, 1, 0], [1, 3, 1]]);
+        assert_eq!(m.rref(), [0, 1]);
+        assert_eq!(m, Matrix::from([[5, 0, -1], [0, 5, 2]]));
+
+        let mut m = Matrix::from([[0, 2, 4], [0, 1, 2], [0, 0, 0]]);
+        assert_eq!(m.rref(), [1]);
+        assert_eq!(m.row(0), [0, 1, 2]);
+        assert_eq!(m.row(1), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_nullspace() {
+        let a = Matrix::from([[1, 2, 3, 4], [2, 4, 7, 9], [3, 6, 10, 13]]);
+        let nullspace = a.nullspace();
+        assert_eq!(nullspace.len(), 4 - a.rank());
+        for v in &nullspace {
+            assert_eq!(multiply(&a, v), [0, 0, 0]);
+        }
+        assert_eq!(nullspace, [vec![-2, 1, 0, 0], vec![-1, 0, -1, 1]]);
+
+        let a = Matrix::from([[3, 2]]);
+        assert_eq!(a.nullspace(), [vec![-2, 3]]);
+    }
+
+    #[test]
+    fn test_system() {
+        // The first machine of the 2025 day 10 sample.
+        let buttons: [&[usize]; 6] = [&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        let a = Matrix::from_fn(4, 6, |r, c| buttons[c].contains(&r) as i128);
+        let b = [3, 5, 4, 7];
+        let system = LinearSystem::new(&a, &b);
+        assert!(system.is_consistent());
+        assert!(system.has_integer_solution());
+        assert_eq!(system.free().len(), 2);
+
+        let solutions = system.solutions([0..=7, 0..=7]).collect::<Vec<_>>();
+        assert!(!solutions.is_empty());
+        for x in &solutions {
+            assert_eq!(multiply(&a, x), b);
+        }
+        let best = solutions
+            .iter()
+            .filter(|x| x.iter().all(|&v| v >= 0))
+            .map(|x| x.iter().sum::<i128>())
+            .min();
+        assert_eq!(best, Some(10));
+
+        // Moving along the nullspace keeps a solution a solution.
+        let x = &solutions[0];
+        for v in system.nullspace() {
+            let moved = x.iter().zip(&v).map(|(x, v)| x + 3 * v).collect::<Vec<_>>();
+            assert_eq!(multiply(&a, &moved), b);
+        }
+    }
+
+    #[test]
+    fn test_no_solutions() {
+        // x + y = 1 and x + y = 2.
+        let system = LinearSystem::new(&Matrix::from([[1, 1], [1, 1]]), &[1, 2]);
+        assert!(!system.is_consistent());
+        assert_eq!(system.solve(&[0]), None);
+        assert_eq!(system.solutions([0..=10]).count(), 0);
+
+        // 2x = 3 has a solution, just not an integer one.
+        let system = LinearSystem::new(&Matrix::from([[2]]), &[3]);
+        assert!(system.is_consistent());
+        assert_eq!(system.solve(&[]), None);
+        assert!(!system.has_integer_solution());
+        let system = LinearSystem::new(&Matrix::from([[2]]), &[4]);
+        assert!(system.has_integer_solution());
+        assert_eq!(system.solutions([]).collect::<Vec<_>>(), [vec![2]]);
+
+        // Neither row rules it out alone, but x + y = 1 and x - y = 0 means 2x = 1.
+        let system = LinearSystem::new(&Matrix::from([[1, 1], [1, -1]]), &[1, 0]);
+        assert!(system.is_consistent());
+        assert!(!system.has_integer_solution());
+        let system = LinearSystem::new(&Matrix::from([[6, 10, 15]]), &[1]);
+        assert!(system.has_integer_solution());
+        assert!(!LinearSystem::new(&Matrix::from([[2, -2]]), &[1]).has_integer_solution());
+    }
+}
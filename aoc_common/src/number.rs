@@ -0,0 +1,266 @@
+use rustc_hash::FxHashMap;
+
+/// The greatest common divisor of `a` and `b` along with `x` and `y` where `a * x + b * y = g`.
+/// The divisor is never negative.
+///
+/// <https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm>
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    match r0 < 0 {
+        true => (-r0, -x0, -y0),
+        false => (r0, x0, y0),
+    }
+}
+
+/// The `x` in `0..m` where `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solve a system of congruences `x ≡ r (mod m)`. The moduli don't have to be coprime. Returns the
+/// smallest non-negative `x` along with the lcm of the moduli that it repeats at, or `None` if the
+/// congruences contradict each other (or the lcm doesn't fit).
+///
+/// <https://en.wikipedia.org/wiki/Chinese_remainder_theorem>
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let (mut r0, mut m0) = (0, 1);
+    for (r1, m1) in congruences {
+        if m1 <= 0 {
+            return None;
+        }
+        let r1 = r1.rem_euclid(m1);
+
+        // We want r0 + m0 * t ≡ r1 (mod m1), which only has a solution if the gcd divides the
+        // difference.
+        let (g, s, _) = extended_gcd(m0, m1);
+        let diff = r1 - r0;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = m1 / g;
+        let t = ((diff / g).rem_euclid(step) as u128)
+            .mul_mod(s.rem_euclid(step) as u128, step as u128) as i128;
+
+        let lcm = m0.checked_mul(step)?;
+        r0 = (r0 + m0.checked_mul(t)?).rem_euclid(lcm);
+        m0 = lcm;
+    }
+    Some((r0, m0))
+}
+
+/// Modular arithmetic that can't overflow on the way to the answer.
+pub trait ModArith: Copy {
+    /// `self * rhs % m`
+    fn mul_mod(self, rhs: Self, m: Self) -> Self;
+
+    /// `self.pow(exp) % m` by squaring.
+    fn pow_mod(self, exp: Self, m: Self) -> Self;
+}
+
+impl ModArith for u64 {
+    fn mul_mod(self, rhs: u64, m: u64) -> u64 {
+        (self as u128 * rhs as u128 % m as u128) as u64
+    }
+
+    fn pow_mod(self, exp: u64, m: u64) -> u64 {
+        (self as u128).pow_mod(exp as u128, m as u128) as u64
+    }
+}
+
+impl ModArith for u128 {
+    fn mul_mod(self, rhs: u128, m: u128) -> u128 {
+        let (mut a, mut b) = (self % m, rhs % m);
+        if let Some(product) = a.checked_mul(b) {
+            return product % m;
+        }
+
+        // Double and add, keeping every sum below m so it can't overflow.
+        let add = |a: u128, b: u128| match a >= m - b {
+            true => a - (m - b),
+            false => a + b,
+        };
+        let mut result = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                result = add(result, a);
+            }
+            a = add(a, a);
+            b >>= 1;
+        }
+        result
+    }
+
+    fn pow_mod(self, mut exp: u128, m: u128) -> u128 {
+        let mut result = 1 % m;
+        let mut base = self % m;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_mod(base, m);
+            }
+            base = base.mul_mod(base, m);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The smallest `x` where `base.pow(x) ≡ target (mod m)` using baby-step giant-step. `base` and
+/// `m` don't have to be coprime.
+///
+/// <https://en.wikipedia.org/wiki/Baby-step_giant-step>
+pub fn discrete_log(base: u64, target: u64, m: u64) -> Option<u64> {
+    let (mut base, mut target, mut m) = (base % m, target % m, m);
+    if m == 1 {
+        return Some(0);
+    }
+
+    // Divide out the factors base and m share until they're coprime. Each one we take out is a
+    // step we know we've taken, and leaves a coefficient in front of what's left.
+    let (mut coefficient, mut steps) = (1 % m, 0);
+    loop {
+        if coefficient == target {
+            return Some(steps);
+        }
+        let g = gcd(base, m);
+        if g == 1 {
+            break;
+        }
+        if target % g != 0 {
+            return None;
+        }
+        (target, m) = (target / g, m / g);
+        coefficient = coefficient.mul_mod(base / g, m);
+        base %= m;
+        steps += 1;
+    }
+
+    // coefficient * base^(i * n - j) ≡ target, so remember target * base^j for the baby steps
+    // and look for coefficient * base^(i * n) with the giant ones.
+    let n = m.isqrt() + 1;
+    let mut baby = FxHashMap::default();
+    let mut value = target;
+    for j in 0..n {
+        baby.insert(value, j);
+        value = value.mul_mod(base, m);
+    }
+
+    let giant = base.pow_mod(n, m);
+    let mut value = coefficient;
+    for i in 1..=n {
+        value = value.mul_mod(giant, m);
+        if let Some(&j) = baby.get(&value) {
+            return Some(i * n - j + steps);
+        }
+    }
+    None
+}
+
+/// The sum of the divisors of every number below `limit` (with 0 for 0).
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit];
+    for d in 1..limit {
+        for n in (d..limit).step_by(d) {
+            sums[n] += d as u64;
+        }
+    }
+    sums
+}
+
+/// Like `divisor_sums`, but each divisor only counts for its first `multiples` multiples.
+pub fn divisor_sums_within(limit: usize, multiples: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit];
+    for d in 1..limit {
+        for n in (d..limit).step_by(d).take(multiples) {
+            sums[n] += d as u64;
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 5), (1, 2)]), Some((9, 10)));
+
+        // Moduli that share factors.
+        assert_eq!(crt([(2, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt([(1, 6), (2, 4)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // Big moduli where the products don't fit in an i128 along the way.
+        let (p, q) = (1_000_000_000_000_000_003, 999_999_999_999_999_989);
+        let (x, m) = crt([(5, p), (7, q)]).unwrap();
+        assert_eq!((x % p, x % q, m), (5, 7, p * q));
+    }
+
+    #[test]
+    fn test_mod_arith() {
+        assert_eq!(252533u64.pow_mod(0, 33554393), 1);
+        assert_eq!(252533u64.pow_mod(2, 33554393), 252533 * 252533 % 33554393);
+        assert_eq!(u64::MAX.mul_mod(u64::MAX, 1_000_000_007), 114944269);
+        assert_eq!(3u64.pow_mod(200, 1_000_000_007), 136318165);
+
+        let m = u128::MAX - 158;
+        assert_eq!((m - 1).mul_mod(m - 1, m), 1);
+        assert_eq!((m - 1).pow_mod(3, m), m - 1);
+        assert_eq!(2u128.pow_mod(127, 1 << 127), 0);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        // The powers of 252533 repeat every 16777196.
+        let (base, m) = (252533, 33554393);
+        let target = base.pow_mod(18_168_397, m);
+        assert_eq!(discrete_log(base, target, m), Some(18_168_397 - 16_777_196));
+
+        // Check small moduli against walking the powers, including ones sharing factors with the
+        // base and targets that never come up.
+        for m in 1..40u64 {
+            for base in 0..m {
+                for target in 0..m {
+                    let expected = (0..2 * m).find(|&x| base.pow_mod(x, m) == target % m);
+                    assert_eq!(
+                        discrete_log(base, target, m),
+                        expected,
+                        "{base}^x = {target} mod {m}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_divisor_sums() {
+        assert_eq!(divisor_sums(10), [0, 1, 3, 4, 7, 6, 12, 8, 15, 13]);
+        assert_eq!(divisor_sums_within(10, 2), [0, 1, 3, 3, 6, 5, 9, 7, 12, 9]);
+    }
+}
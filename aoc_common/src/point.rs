@@ -0,0 +1,390 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::Result;
+
+/// The numbers a point can be made of. The checked operations let neighbors stay inside the type
+/// (no negative `usize` coordinates).
+pub trait Coord:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + FromStr
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn abs_diff(self, rhs: Self) -> Self {
+        if self > rhs { self - rhs } else { rhs - self }
+    }
+}
+
+macro_rules! coord_int {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+coord_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! coord_float {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs)
+                }
+            }
+        )*
+    };
+}
+
+coord_float!(f32, f64);
+
+/// A point (or vector) on a plane. Ordering is by x and then y.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    /// The four orthogonal neighbors. Neighbors that don't fit in `T` are skipped.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        let (x, y) = (self.x, self.y);
+        [
+            x.checked_add(T::ONE).map(|x| Self::new(x, y)),
+            x.checked_sub(T::ONE).map(|x| Self::new(x, y)),
+            y.checked_add(T::ONE).map(|y| Self::new(x, y)),
+            y.checked_sub(T::ONE).map(|y| Self::new(x, y)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// The eight surrounding neighbors, including diagonals.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        let (x, y) = (self.x, self.y);
+        let xs = [x.checked_sub(T::ONE), Some(x), x.checked_add(T::ONE)];
+        let ys = [y.checked_sub(T::ONE), Some(y), y.checked_add(T::ONE)];
+        ys.into_iter()
+            .flat_map(move |ny| xs.into_iter().map(move |nx| (nx, ny)))
+            .filter_map(|(nx, ny)| Some(Self::new(nx?, ny?)))
+            .filter(move |p| *p != self)
+    }
+
+    pub fn manhattan(&self, rhs: &Self) -> T {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
+    }
+
+    pub fn chebyshev(&self, rhs: &Self) -> T {
+        let (dx, dy) = (self.x.abs_diff(rhs.x), self.y.abs_diff(rhs.y));
+        if dx > dy { dx } else { dy }
+    }
+
+    /// Parse "y,x" (e.g. "row,col"). `FromStr` parses "x,y".
+    pub fn from_yx(s: &str) -> Result<Self> {
+        let p: Self = s.parse()?;
+        Ok(Self::new(p.y, p.x))
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point2<T> {
+    /// Turn 90 degrees counter-clockwise with y pointing up (clockwise on a screen where y grows
+    /// downward).
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Turn 90 degrees clockwise with y pointing up.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+/// A point (or vector) in space. Ordering is by x, y and then z.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    /// The six orthogonal neighbors. Neighbors that don't fit in `T` are skipped.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let Self { x, y, z } = self;
+        [
+            x.checked_add(T::ONE).map(|x| Self::new(x, y, z)),
+            x.checked_sub(T::ONE).map(|x| Self::new(x, y, z)),
+            y.checked_add(T::ONE).map(|y| Self::new(x, y, z)),
+            y.checked_sub(T::ONE).map(|y| Self::new(x, y, z)),
+            z.checked_add(T::ONE).map(|z| Self::new(x, y, z)),
+            z.checked_sub(T::ONE).map(|z| Self::new(x, y, z)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn manhattan(&self, rhs: &Self) -> T {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)
+    }
+
+    pub fn chebyshev(&self, rhs: &Self) -> T {
+        let max = |a: T, b: T| if a > b { a } else { b };
+        max(
+            max(self.x.abs_diff(rhs.x), self.y.abs_diff(rhs.y)),
+            self.z.abs_diff(rhs.z),
+        )
+    }
+
+    /// The square of the straight line distance. Good enough for ordering and no sqrt.
+    pub fn distance_squared(&self, rhs: &Self) -> T {
+        let (dx, dy, dz) = (
+            self.x.abs_diff(rhs.x),
+            self.y.abs_diff(rhs.y),
+            self.z.abs_diff(rhs.z),
+        );
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+// Split "a,b[,c]" into exactly N trimmed coordinates.
+fn coords<T: Coord, const N: usize>(s: &str) -> Result<[T; N]> {
+    let parts: Vec<&str> = s.trim().split(',').map(str::trim).collect();
+    if parts.len() != N {
+        return Err(format!("expected {N} coordinates in '{s}'").into());
+    }
+
+    let mut coords = [T::ZERO; N];
+    for (coord, part) in coords.iter_mut().zip(parts) {
+        *coord = part
+            .parse()
+            .map_err(|_| format!("invalid coordinate '{part}' in '{s}'"))?;
+    }
+    Ok(coords)
+}
+
+impl<T: Coord> FromStr for Point2<T> {
+    type Err = Box<dyn std::error::Error>;
+
+    /// Parse "x,y".
+    fn from_str(s: &str) -> Result<Self> {
+        let [x, y] = coords(s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<T: Coord> FromStr for Point3<T> {
+    type Err = Box<dyn std::error::Error>;
+
+    /// Parse "x,y,z".
+    fn from_str(s: &str) -> Result<Self> {
+        let [x, y, z] = coords(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// Component-wise operators for points (by value and by reference), scalar multiplication and
+// division, and negation.
+macro_rules! ops {
+    ($point:ident, $($field:ident),*) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Add<Output = T> + Copy> Add for &$point<T> {
+            type Output = $point<T>;
+            fn add(self, rhs: Self) -> $point<T> {
+                *self + *rhs
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T> + Copy> Sub for &$point<T> {
+            type Output = $point<T>;
+            fn sub(self, rhs: Self) -> $point<T> {
+                *self - *rhs
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $point<T> {
+            type Output = Self;
+            fn div(self, rhs: T) -> Self {
+                Self { $($field: self.$field / rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$field *= rhs;)*
+            }
+        }
+
+        impl<T: DivAssign + Copy> DivAssign<T> for $point<T> {
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$field /= rhs;)*
+            }
+        }
+    };
+}
+
+ops!(Point2, x, y);
+ops!(Point3, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(3, -4));
+        assert_eq!(a + b, Point2::new(4, -2));
+        assert_eq!(a - b, Point2::new(-2, 6));
+        assert_eq!(b * 2, Point2::new(6, -8));
+        assert_eq!(-a, Point2::new(-1, -2));
+
+        let mut c = a;
+        c += b;
+        c *= 3;
+        assert_eq!(c, Point2::new(12, -6));
+
+        let p = Point3::new(1, 2, 3) + Point3::new(1, 1, 1);
+        assert_eq!(p, Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let north = Point2::new(0, 1);
+        assert_eq!(north.rotate_right(), Point2::new(1, 0));
+        assert_eq!(north.rotate_left(), Point2::new(-1, 0));
+        assert_eq!(
+            north.rotate_left().rotate_left().rotate_left(),
+            north.rotate_right()
+        );
+    }
+
+    #[test]
+    fn test_distance() {
+        let (a, b) = (Point2::new(1usize, 8), Point2::new(4usize, 2));
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.chebyshev(&b), 6);
+
+        let (a, b) = (Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, -2.0, 2.0));
+        assert_eq!(a.distance_squared(&b), 9.0);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(&Point3::new(-1, 2, 6)), 5);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Point2::new(0usize, 0);
+        let mut n: Vec<_> = origin.neighbors4().collect();
+        n.sort();
+        assert_eq!(n, vec![Point2::new(0, 1), Point2::new(1, 0)]);
+        assert_eq!(origin.neighbors8().count(), 3);
+        assert_eq!(Point2::new(5i32, 5).neighbors8().count(), 8);
+        assert_eq!(Point3::new(0i64, 0, 0).neighbors6().count(), 6);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,4".parse::<Point2<i32>>().unwrap(), Point2::new(3, 4));
+        assert_eq!(Point2::<i32>::from_yx("3, 4").unwrap(), Point2::new(4, 3));
+        assert_eq!(
+            "1,-2,3".parse::<Point3<i64>>().unwrap(),
+            Point3::new(1, -2, 3)
+        );
+        assert!("1,2,3".parse::<Point2<i32>>().is_err());
+        assert!("1,x".parse::<Point2<i32>>().is_err());
+    }
+}
@@ -0,0 +1,530 @@
+use crate::Point2;
+
+/// A shape made of cells on a grid. The cells are kept sorted and pushed up against the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polyomino {
+    cells: Vec<Point2<usize>>,
+    width: usize,
+    height: usize,
+}
+
+impl Polyomino {
+    pub fn new(cells: impl IntoIterator<Item = Point2<usize>>) -> Self {
+        let mut cells = cells.into_iter().collect::<Vec<_>>();
+        let min_x = cells.iter().map(|p| p.x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|p| p.y).min().unwrap_or(0);
+        for p in &mut cells {
+            *p = Point2::new(p.x - min_x, p.y - min_y);
+        }
+        cells.sort_unstable();
+        cells.dedup();
+
+        let width = cells.iter().map(|p| p.x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|p| p.y + 1).max().unwrap_or(0);
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn cells(&self) -> &[Point2<usize>] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate(&self) -> Self {
+        Self::new(
+            self.cells
+                .iter()
+                .map(|p| Point2::new(self.height - 1 - p.y, p.x)),
+        )
+    }
+
+    /// Mirrored left to right.
+    pub fn flip(&self) -> Self {
+        Self::new(
+            self.cells
+                .iter()
+                .map(|p| Point2::new(self.width - 1 - p.x, p.y)),
+        )
+    }
+
+    /// Every distinct way the shape can be turned and flipped, starting with itself.
+    pub fn variations(&self) -> Vec<Self> {
+        let mut variations: Vec<Self> = vec![];
+        let mut current = self.clone();
+        for _ in 0..4 {
+            for variation in [current.clone(), current.flip()] {
+                if !variations.contains(&variation) {
+                    variations.push(variation);
+                }
+            }
+            current = current.rotate();
+        }
+        variations
+    }
+}
+
+/// Lines of text where every '#' is a cell.
+impl From<&str> for Polyomino {
+    fn from(value: &str) -> Self {
+        Self::new(value.lines().enumerate().flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| Point2::new(x, y))
+        }))
+    }
+}
+
+/// Fits `counts[i]` copies of each of the shapes onto a `width` by `height` board, turned and
+/// flipped however they need to be. Cells can be left empty.
+///
+/// Boards without the area for the shapes are turned down and boards with a spot for every
+/// shape's bounding box are filled without looking any further. Anything else is an exact cover
+/// search with dancing links (Algorithm X). Each shape is a column that has to be covered once per
+/// copy and each cell is an optional column that can be covered at most once. It always branches
+/// on the shape with the fewest places left to go, and gives up on a branch once the cells the
+/// shapes could still reach can't hold them.
+///
+/// <https://en.wikipedia.org/wiki/Dancing_Links>
+#[derive(Debug, Clone, Copy)]
+pub struct Packing<'a> {
+    width: usize,
+    height: usize,
+    shapes: &'a [Polyomino],
+    counts: &'a [usize],
+}
+
+/// Where one copy of a shape went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub shape: usize,
+    pub cells: Vec<Point2<usize>>,
+}
+
+impl<'a> Packing<'a> {
+    pub fn new(width: usize, height: usize, shapes: &'a [Polyomino], counts: &'a [usize]) -> Self {
+        assert_eq!(shapes.len(), counts.len(), "need a count for each shape");
+        Self {
+            width,
+            height,
+            shapes,
+            counts,
+        }
+    }
+
+    /// Where every shape goes, or `None` if they don't all fit.
+    pub fn solve(&self) -> Option<Vec<Placement>> {
+        let needed = self
+            .shapes
+            .iter()
+            .zip(self.counts)
+            .map(|(shape, count)| shape.area() * count)
+            .sum::<usize>();
+        if needed > self.width * self.height {
+            return None;
+        }
+        self.slots().or_else(|| self.search())
+    }
+
+    // Each copy of each shape, in order.
+    fn copies(&self) -> impl Iterator<Item = usize> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .flat_map(|(shape, &count)| std::iter::repeat_n(shape, count))
+    }
+
+    // Give every copy a box of its own as big as the biggest shape, if there's room.
+    fn slots(&self) -> Option<Vec<Placement>> {
+        let used = || self.copies().map(|shape| &self.shapes[shape]);
+        let width = used().map(Polyomino::width).max().unwrap_or(0).max(1);
+        let height = used().map(Polyomino::height).max().unwrap_or(0).max(1);
+        let (columns, rows) = (self.width / width, self.height / height);
+        if columns * rows < self.copies().count() {
+            return None;
+        }
+
+        let slots = (0..rows).flat_map(|r| (0..columns).map(move |c| Point2::new(c, r)));
+        let placements = self
+            .copies()
+            .zip(slots)
+            .map(|(shape, slot)| Placement {
+                shape,
+                cells: self.shapes[shape]
+                    .cells()
+                    .iter()
+                    .map(|p| Point2::new(slot.x * width + p.x, slot.y * height + p.y))
+                    .collect(),
+            })
+            .collect();
+        Some(placements)
+    }
+
+    fn search(&self) -> Option<Vec<Placement>> {
+        // Every place each shape can go, as the board cells it covers.
+        let mut options = vec![];
+        for (shape, polyomino) in self.shapes.iter().enumerate() {
+            if self.counts[shape] == 0 {
+                continue;
+            }
+            for v in polyomino.variations() {
+                let xs = (self.width + 1).saturating_sub(v.width());
+                let ys = (self.height + 1).saturating_sub(v.height());
+                for (x, y) in (0..ys).flat_map(|y| (0..xs).map(move |x| (x, y))) {
+                    let cells = v.cells().iter().map(|p| (y + p.y) * self.width + x + p.x);
+                    options.push((shape, cells.collect::<Vec<_>>()));
+                }
+            }
+        }
+
+        let areas = self.shapes.iter().map(Polyomino::area).collect();
+        let mut links = Links::new(areas, self.counts, self.width * self.height, &options);
+        let mut chosen = vec![];
+        if !links.search(&mut chosen) {
+            return None;
+        }
+
+        let placements = chosen
+            .iter()
+            .map(|&option| {
+                let (shape, cells) = &options[option];
+                Placement {
+                    shape: *shape,
+                    cells: cells
+                        .iter()
+                        .map(|cell| Point2::new(cell % self.width, cell / self.width))
+                        .collect(),
+                }
+            })
+            .collect();
+        Some(placements)
+    }
+}
+
+// The exact cover matrix as dancing links. There's a column for each shape that has to be covered
+// once per copy and an optional column for each cell of the board, which at most one placement
+// can cover. Each row is one place a shape can go.
+//
+// Node 0 is the root, the shapes' headers come next and then the cells'. Only the shapes are
+// linked to the root, so a solution doesn't have to cover every cell.
+struct Links {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    // The header of the column each node is in.
+    column: Vec<usize>,
+    // The row each node is in.
+    row: Vec<usize>,
+    // How many nodes are still linked into each column, by header.
+    size: Vec<usize>,
+    covered: Vec<bool>,
+    // How many more copies of each shape to place, and how many cells each one takes.
+    needs: Vec<usize>,
+    areas: Vec<usize>,
+}
+
+impl Links {
+    fn new(
+        areas: Vec<usize>,
+        counts: &[usize],
+        cells: usize,
+        options: &[(usize, Vec<usize>)],
+    ) -> Self {
+        let headers = 1 + areas.len() + cells;
+        let mut links = Self {
+            left: (0..headers).collect(),
+            right: (0..headers).collect(),
+            up: (0..headers).collect(),
+            down: (0..headers).collect(),
+            column: (0..headers).collect(),
+            row: vec![usize::MAX; headers],
+            size: vec![0; headers],
+            covered: vec![false; headers],
+            needs: counts.to_vec(),
+            areas,
+        };
+
+        for shape in (0..counts.len()).filter(|&shape| counts[shape] > 0) {
+            let header = 1 + shape;
+            links.left[header] = links.left[0];
+            links.right[header] = 0;
+            links.right[links.left[0]] = header;
+            links.left[0] = header;
+        }
+
+        let cell_header = |cell| 1 + counts.len() + cell;
+        for (row, (shape, cells)) in options.iter().enumerate() {
+            let first = links.column.len();
+            for header in std::iter::once(1 + shape).chain(cells.iter().map(|&c| cell_header(c))) {
+                let node = links.column.len();
+                links.column.push(header);
+                links.row.push(row);
+                links.up.push(links.up[header]);
+                links.down.push(header);
+                links.down[links.up[header]] = node;
+                links.up[header] = node;
+                links.size[header] += 1;
+                links.left.push(node - 1);
+                links.right.push(node + 1);
+            }
+            let last = links.column.len() - 1;
+            links.left[first] = last;
+            links.right[last] = first;
+        }
+        links
+    }
+
+    // Take a node out of its column, or put it back.
+    fn unlink(&mut self, node: usize) {
+        self.down[self.up[node]] = self.down[node];
+        self.up[self.down[node]] = self.up[node];
+        self.size[self.column[node]] -= 1;
+    }
+
+    fn relink(&mut self, node: usize) {
+        self.down[self.up[node]] = node;
+        self.up[self.down[node]] = node;
+        self.size[self.column[node]] += 1;
+    }
+
+    // Take a column out along with every row that uses it.
+    fn cover(&mut self, header: usize) {
+        self.right[self.left[header]] = self.right[header];
+        self.left[self.right[header]] = self.left[header];
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                self.unlink(j);
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+        self.covered[header] = true;
+    }
+
+    fn uncover(&mut self, header: usize) {
+        self.covered[header] = false;
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                self.relink(j);
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        self.right[self.left[header]] = header;
+        self.left[self.right[header]] = header;
+    }
+
+    // Use the row `node` is in for its shape and cover its cells. Once that's the last copy of
+    // the shape, its column goes too.
+    fn select(&mut self, node: usize) {
+        let shape = self.column[node] - 1;
+        self.needs[shape] -= 1;
+        if self.needs[shape] == 0 {
+            self.cover(self.column[node]);
+        }
+        let mut j = self.right[node];
+        while j != node {
+            self.cover(self.column[j]);
+            j = self.right[j];
+        }
+    }
+
+    fn unselect(&mut self, node: usize) {
+        let mut j = self.left[node];
+        while j != node {
+            self.uncover(self.column[j]);
+            j = self.left[j];
+        }
+        let shape = self.column[node] - 1;
+        if self.needs[shape] == 0 {
+            self.uncover(self.column[node]);
+        }
+        self.needs[shape] += 1;
+    }
+
+    // Take a whole row out of the matrix, or put it back.
+    fn hide(&mut self, node: usize) {
+        let mut j = node;
+        loop {
+            self.unlink(j);
+            j = self.right[j];
+            if j == node {
+                break;
+            }
+        }
+    }
+
+    fn unhide(&mut self, node: usize) {
+        let mut j = self.left[node];
+        loop {
+            self.relink(j);
+            if j == node {
+                break;
+            }
+            j = self.left[j];
+        }
+    }
+
+    fn search(&mut self, chosen: &mut Vec<usize>) -> bool {
+        // Find the shape with the fewest places to go.
+        let mut best: Option<usize> = None;
+        let mut needed = 0;
+        let mut header = self.right[0];
+        while header != 0 {
+            let shape = header - 1;
+            if self.size[header] < self.needs[shape] {
+                return false;
+            }
+            needed += self.areas[shape] * self.needs[shape];
+            if best.is_none_or(|fewest| self.size[header] < self.size[fewest]) {
+                best = Some(header);
+            }
+            header = self.right[header];
+        }
+
+        let Some(header) = best else {
+            return true;
+        };
+
+        // Give up once the cells that could still be used can't hold what's left.
+        let cells = 1 + self.areas.len()..self.size.len();
+        let reachable = cells
+            .filter(|&cell| !self.covered[cell] && self.size[cell] > 0)
+            .count();
+        if reachable < needed {
+            return false;
+        }
+
+        // Copies of a shape are interchangeable, so once a row has been tried it's left out of
+        // the rest of this branch, which keeps each set of rows from coming up in every order.
+        let mut tried = vec![];
+        let mut node = self.down[header];
+        while node != header {
+            self.select(node);
+            chosen.push(self.row[node]);
+            if self.search(chosen) {
+                return true;
+            }
+            chosen.pop();
+            self.unselect(node);
+
+            self.hide(node);
+            tried.push(node);
+            node = self.down[node];
+        }
+        for node in tried.into_iter().rev() {
+            self.unhide(node);
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitSet;
+
+    // The shapes from the 2025 day 12 sample.
+    const SHAPES: [&str; 6] = [
+        "###\n##.\n##.",
+        "###\n##.\n.##",
+        ".##\n###\n##.",
+        "##.\n###\n##.",
+        "###\n#..\n###",
+        "###\n.#.\n###",
+    ];
+
+    fn shapes() -> Vec<Polyomino> {
+        SHAPES.into_iter().map(Polyomino::from).collect()
+    }
+
+    // Every copy is on the board, nothing overlaps and each copy is one of the shape's variations.
+    fn check(width: usize, height: usize, counts: &[usize], placements: &[Placement]) {
+        let shapes = shapes();
+        let mut used = BitSet::new(width * height);
+        for placement in placements {
+            let shape = Polyomino::new(placement.cells.iter().copied());
+            assert!(shapes[placement.shape].variations().contains(&shape));
+            for p in &placement.cells {
+                assert!(p.x < width && p.y < height);
+                assert!(!used.test(p.y * width + p.x));
+                used.set(p.y * width + p.x);
+            }
+        }
+        for (shape, &count) in counts.iter().enumerate() {
+            let placed = placements.iter().filter(|p| p.shape == shape).count();
+            assert_eq!(placed, count);
+        }
+    }
+
+    #[test]
+    fn test_variations() {
+        let shapes = shapes();
+        assert_eq!(shapes[0].area(), 7);
+        assert_eq!((shapes[0].width(), shapes[0].height()), (3, 3));
+        assert_eq!(shapes[0].variations().len(), 8);
+        assert_eq!(shapes[4].variations().len(), 4);
+        assert_eq!(shapes[5].variations().len(), 2);
+        assert_eq!(shapes[0].rotate().rotate().rotate().rotate(), shapes[0]);
+        assert_eq!(shapes[0].flip().flip(), shapes[0]);
+
+        // Offsets are dropped.
+        let domino = Polyomino::from("..\n.#\n.#");
+        assert_eq!(domino.cells(), [Point2::new(0, 0), Point2::new(0, 1)]);
+        assert_eq!(domino.rotate(), Polyomino::from("##"));
+        assert_eq!(domino.variations().len(), 2);
+    }
+
+    #[test]
+    fn test_packing() {
+        let shapes = shapes();
+        for (width, height, counts, fits) in [
+            (4, 4, [0, 0, 0, 0, 2, 0], true),
+            (12, 5, [1, 0, 1, 0, 2, 2], true),
+            (12, 5, [1, 0, 1, 0, 3, 2], false),
+        ] {
+            let placements = Packing::new(width, height, &shapes, &counts).solve();
+            assert_eq!(placements.is_some(), fits, "{width}x{height} {counts:?}");
+            if let Some(placements) = placements {
+                check(width, height, &counts, &placements);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pruning() {
+        let shapes = shapes();
+
+        // Not enough area.
+        let counts = [0, 0, 0, 0, 0, 3];
+        assert_eq!(Packing::new(4, 5, &shapes, &counts).solve(), None);
+
+        // Plenty of room, so every shape gets its own box.
+        let counts = [3, 1, 4, 1, 5, 9];
+        let placements = Packing::new(20, 20, &shapes, &counts).solve().unwrap();
+        check(20, 20, &counts, &placements);
+
+        // Nothing to place.
+        let counts = [0; 6];
+        assert_eq!(Packing::new(0, 0, &shapes, &counts).solve(), Some(vec![]));
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::extended_gcd;
+
+/// An exact fraction, always kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numer: 0, denom: 1 };
+    pub const ONE: Self = Self { numer: 1, denom: 1 };
+
+    /// `numer / denom`. Panics if `denom` is zero.
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "zero denominator");
+        let g = extended_gcd(numer, denom).0;
+        let sign = denom.signum();
+        Self {
+            numer: sign * numer / g,
+            denom: sign * denom / g,
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    /// The largest integer no bigger than this.
+    pub fn floor(&self) -> i128 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    /// The smallest integer no smaller than this.
+    pub fn ceil(&self) -> i128 {
+        -(-self.numer).div_euclid(self.denom)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Only scale up by what the denominators don't share to keep things small.
+        let g = extended_gcd(self.denom, rhs.denom).0;
+        Self::new(
+            self.numer * (rhs.denom / g) + rhs.numer * (self.denom / g),
+            self.denom / g * rhs.denom,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Cancel across before multiplying so nothing overflows that doesn't have to.
+        let g1 = extended_gcd(self.numer, rhs.denom).0.max(1);
+        let g2 = extended_gcd(rhs.numer, self.denom).0.max(1);
+        Self {
+            numer: (self.numer / g1) * (rhs.numer / g2),
+            denom: (self.denom / g2) * (rhs.denom / g1),
+        }
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "division by zero");
+        let sign = rhs.numer.signum();
+        self * Self {
+            numer: sign * rhs.denom,
+            denom: sign * rhs.numer,
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            _ => write!(f, "{}/{}", self.numer, self.denom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        let third = Rational::new(-1, -3);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(third, Rational::new(1, 3));
+        assert_eq!(Rational::new(3, -6), -half);
+
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / -third, Rational::new(-3, 2));
+        assert_eq!(half - half, Rational::ZERO);
+        assert_eq!(third * Rational::from(3), Rational::ONE);
+        assert!(third < half && -half < third);
+
+        assert_eq!(
+            (Rational::new(7, 2).floor(), Rational::new(7, 2).ceil()),
+            (3, 4)
+        );
+        assert_eq!(
+            (Rational::new(-7, 2).floor(), Rational::new(-7, 2).ceil()),
+            (-4, -3)
+        );
+        assert_eq!(
+            (Rational::from(5).floor(), Rational::from(5).ceil()),
+            (5, 5)
+        );
+        assert_eq!(Rational::new(-3, 4).to_string(), "-3/4");
+        assert_eq!(Rational::from(8).to_string(), "8");
+    }
+}
@@ -0,0 +1,460 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::Coord;
+
+/// A graph to search where every move is one step.
+pub trait StateSpace {
+    type State: Clone + Eq + Hash;
+
+    fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = Self::State>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// The states that can move to `state`. Only the bidirectional search walks these. By default
+    /// they are the successors, which is right whenever a move can be undone.
+    fn predecessors(&self, state: &Self::State) -> impl IntoIterator<Item = Self::State> {
+        self.successors(state)
+    }
+}
+
+/// A graph to search where each move has a cost.
+pub trait WeightedStateSpace {
+    type State: Clone + Eq + Hash;
+    type Cost: Coord + Ord;
+
+    fn successors(
+        &self,
+        state: &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to a goal, used by A*. It must never overestimate
+    /// and, for the path counts to be right, shouldn't drop by more than the cost of any move.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::ZERO
+    }
+}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// The most states waiting in the frontier at once.
+    pub frontier_peak: usize,
+}
+
+/// The result of a successful search.
+#[derive(Debug, Clone)]
+pub struct ShortestPath<S, C = usize> {
+    /// The states from the start to the goal, both included.
+    pub path: Vec<S>,
+    pub cost: C,
+    /// The number of different paths to the goal with the same cost.
+    pub count: usize,
+    pub stats: Stats,
+}
+
+impl<S, C> ShortestPath<S, C> {
+    /// The goal state that was reached.
+    pub fn goal(&self) -> &S {
+        self.path.last().unwrap()
+    }
+}
+
+/// Breadth-first search from `start` to the nearest goal.
+pub fn bfs<G: StateSpace>(space: &G, start: G::State) -> Option<ShortestPath<G::State>> {
+    let mut seen = Visited::new(start, 0);
+    let mut frontier = VecDeque::from([0]);
+    let mut stats = Stats {
+        expanded: 0,
+        frontier_peak: 1,
+    };
+
+    while let Some(i) = frontier.pop_front() {
+        // Everything a step closer has been expanded by now, so the count is final.
+        if space.is_goal(&seen.nodes[i].state) {
+            return Some(seen.shortest_path(i, stats));
+        }
+
+        stats.expanded += 1;
+        let state = seen.nodes[i].state.clone();
+        let steps = seen.nodes[i].cost + 1;
+        for next in space.successors(&state) {
+            if let Some(j) = seen.relax(next, i, steps) {
+                frontier.push_back(j);
+            }
+        }
+        stats.frontier_peak = stats.frontier_peak.max(frontier.len());
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest goal. The heuristic is ignored.
+///
+/// <https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm>
+pub fn dijkstra<G: WeightedStateSpace>(
+    space: &G,
+    start: G::State,
+) -> Option<ShortestPath<G::State, G::Cost>> {
+    best_first(space, start, |_| G::Cost::ZERO)
+}
+
+/// A* search from `start` to the cheapest goal, guided by the space's heuristic.
+///
+/// <https://en.wikipedia.org/wiki/A*_search_algorithm>
+pub fn astar<G: WeightedStateSpace>(
+    space: &G,
+    start: G::State,
+) -> Option<ShortestPath<G::State, G::Cost>> {
+    best_first(space, start, |state| space.heuristic(state))
+}
+
+fn best_first<G: WeightedStateSpace>(
+    space: &G,
+    start: G::State,
+    heuristic: impl Fn(&G::State) -> G::Cost,
+) -> Option<ShortestPath<G::State, G::Cost>> {
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), G::Cost::ZERO, 0))]);
+    let mut seen = Visited::new(start, G::Cost::ZERO);
+    let mut stats = Stats {
+        expanded: 0,
+        frontier_peak: 1,
+    };
+
+    // Ties go to the state with the lower cost so its count is complete before anything it
+    // leads to is expanded.
+    while let Some(Reverse((_, cost, i))) = frontier.pop() {
+        if cost > seen.nodes[i].cost {
+            continue; // We've since found a cheaper way here.
+        }
+        if space.is_goal(&seen.nodes[i].state) {
+            return Some(seen.shortest_path(i, stats));
+        }
+
+        stats.expanded += 1;
+        let state = seen.nodes[i].state.clone();
+        for (next, step) in space.successors(&state) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+            if let Some(j) = seen.relax(next, i, cost) {
+                frontier.push(Reverse((estimate, cost, j)));
+            }
+        }
+        stats.frontier_peak = stats.frontier_peak.max(frontier.len());
+    }
+
+    None
+}
+
+/// Breadth-first search from both ends at once, meeting in the middle. The goal is the given
+/// state rather than `is_goal`, and the backward half walks `predecessors`.
+pub fn bidirectional_bfs<G: StateSpace>(
+    space: &G,
+    start: G::State,
+    goal: G::State,
+) -> Option<ShortestPath<G::State>> {
+    let mut stats = Stats {
+        expanded: 0,
+        frontier_peak: 1,
+    };
+    if start == goal {
+        return Some(ShortestPath {
+            path: vec![start],
+            cost: 0,
+            count: 1,
+            stats,
+        });
+    }
+
+    let mut forward = (Visited::new(start, 0), vec![0]);
+    let mut backward = (Visited::new(goal, 0), vec![0]);
+    while !forward.1.is_empty() && !backward.1.is_empty() {
+        stats.frontier_peak = stats.frontier_peak.max(forward.1.len() + backward.1.len());
+
+        // Grow the smaller side by a whole layer. Stopping part way through a layer could settle
+        // on a meeting that isn't the shortest.
+        let forwards = forward.1.len() <= backward.1.len();
+        let ((seen, layer), (other, _)) = match forwards {
+            true => (&mut forward, &backward),
+            false => (&mut backward, &forward),
+        };
+
+        let mut next = vec![];
+        for &i in layer.iter() {
+            stats.expanded += 1;
+            let state = seen.nodes[i].state.clone();
+            let steps = seen.nodes[i].cost + 1;
+            let mut visit = |s| next.extend(seen.relax(s, i, steps));
+            match forwards {
+                true => space.successors(&state).into_iter().for_each(&mut visit),
+                false => space.predecessors(&state).into_iter().for_each(&mut visit),
+            }
+        }
+
+        // Each shortest path crosses the new layer exactly once, so summing over the meetings
+        // in it counts them all.
+        let meetings = next
+            .iter()
+            .filter_map(|&i| {
+                let j = *other.index.get(&seen.nodes[i].state)?;
+                Some((i, j, seen.nodes[i].cost + other.nodes[j].cost))
+            })
+            .collect::<Vec<_>>();
+        if let Some(&(i, j, cost)) = meetings.iter().min_by_key(|(_, _, cost)| *cost) {
+            let count =
+                meetings
+                    .iter()
+                    .filter(|m| m.2 == cost)
+                    .fold(0usize, |count, &(i, j, _)| {
+                        count.saturating_add(
+                            seen.nodes[i].count.saturating_mul(other.nodes[j].count),
+                        )
+                    });
+
+            let (mut path, rest) = match forwards {
+                true => (seen.path(i), other.path(j)),
+                false => (other.path(j), seen.path(i)),
+            };
+            path.extend(rest.into_iter().rev().skip(1));
+            return Some(ShortestPath {
+                path,
+                cost,
+                count,
+                stats,
+            });
+        }
+        *layer = next;
+    }
+
+    None
+}
+
+/// Every state within `max_steps` of `start` along with how many steps it takes to get there.
+pub fn reachable<G: StateSpace>(
+    space: &G,
+    start: G::State,
+    max_steps: usize,
+) -> FxHashMap<G::State, usize> {
+    let mut seen = FxHashMap::default();
+    seen.insert(start.clone(), 0);
+    let mut frontier = VecDeque::from([(start, 0)]);
+
+    while let Some((state, steps)) = frontier.pop_front() {
+        if steps == max_steps {
+            continue;
+        }
+        for next in space.successors(&state) {
+            if !seen.contains_key(&next) {
+                seen.insert(next.clone(), steps + 1);
+                frontier.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    seen
+}
+
+// The states a search has reached. Each remembers the cheapest way it was reached so the path
+// can be rebuilt, and how many ways there are at that cost.
+struct Visited<S, C> {
+    index: FxHashMap<S, usize>,
+    nodes: Vec<Node<S, C>>,
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+    count: usize,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        let mut index = FxHashMap::default();
+        index.insert(start.clone(), 0);
+        Self {
+            index,
+            nodes: vec![Node {
+                state: start,
+                parent: None,
+                cost,
+                count: 1,
+            }],
+        }
+    }
+
+    // Reach `state` from the parent at the given cost. Returns its index if it's new or cheaper
+    // than before (and so needs expanding).
+    fn relax(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        let count = self.nodes[parent].count;
+        match self.index.get(&state) {
+            Some(&i) => {
+                let node = &mut self.nodes[i];
+                if cost < node.cost {
+                    node.parent = Some(parent);
+                    node.cost = cost;
+                    node.count = count;
+                    Some(i)
+                } else {
+                    if cost == node.cost {
+                        node.count = node.count.saturating_add(count);
+                    }
+                    None
+                }
+            }
+            None => {
+                self.index.insert(state.clone(), self.nodes.len());
+                self.nodes.push(Node {
+                    state,
+                    parent: Some(parent),
+                    cost,
+                    count,
+                });
+                Some(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[i].state.clone()];
+        while let Some(parent) = self.nodes[i].parent {
+            path.push(self.nodes[parent].state.clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    fn shortest_path(&self, i: usize, stats: Stats) -> ShortestPath<S, C> {
+        ShortestPath {
+            path: self.path(i),
+            cost: self.nodes[i].cost,
+            count: self.nodes[i].count,
+            stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point2};
+
+    // An open maze where '#' is a wall. Moving onto a digit costs that much, anything else 1.
+    struct Maze {
+        grid: Grid<u8>,
+        goal: Point2<usize>,
+    }
+
+    impl Maze {
+        fn new(rows: &[&str]) -> Self {
+            let grid = Grid::from_bytes(&rows.join("\n")).unwrap().map(|&b| b);
+            let goal = Point2::new(grid.width() - 1, grid.height() - 1);
+            Self { grid, goal }
+        }
+    }
+
+    impl StateSpace for Maze {
+        type State = Point2<usize>;
+
+        fn successors(&self, p: &Self::State) -> impl IntoIterator<Item = Self::State> {
+            self.grid.neighbors4(*p).filter(|p| self.grid[*p] != b'#')
+        }
+
+        fn is_goal(&self, p: &Self::State) -> bool {
+            *p == self.goal
+        }
+    }
+
+    impl WeightedStateSpace for Maze {
+        type State = Point2<usize>;
+        type Cost = usize;
+
+        fn successors(&self, p: &Self::State) -> impl IntoIterator<Item = (Self::State, usize)> {
+            StateSpace::successors(self, p).into_iter().map(|p| {
+                let cost = match self.grid[p] {
+                    b @ b'1'..=b'9' => (b - b'0') as usize,
+                    _ => 1,
+                };
+                (p, cost)
+            })
+        }
+
+        fn is_goal(&self, p: &Self::State) -> bool {
+            *p == self.goal
+        }
+
+        fn heuristic(&self, p: &Self::State) -> usize {
+            p.manhattan(&self.goal)
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = Maze::new(&["...", "...", "..."]);
+        let found = bfs(&maze, Point2::new(0, 0)).unwrap();
+        assert_eq!((found.cost, found.count), (4, 6));
+        assert_eq!(found.path.len(), 5);
+        assert_eq!(*found.goal(), maze.goal);
+        assert!(found.path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert_eq!(found.stats.expanded, 8);
+
+        let maze = Maze::new(&["..#.", "..#.", "..#."]);
+        assert!(bfs(&maze, Point2::new(0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_weighted() {
+        let maze = Maze::new(&[".9...", ".#.#.", "...#.", "##.1.", "...1."]);
+        let found = dijkstra(&maze, Point2::new(0, 0)).unwrap();
+        assert_eq!((found.cost, found.count), (8, 3));
+        assert_eq!(found.path.first(), Some(&Point2::new(0, 0)));
+
+        let guided = astar(&maze, Point2::new(0, 0)).unwrap();
+        assert_eq!((guided.cost, guided.count), (8, 3));
+        assert!(guided.stats.expanded <= found.stats.expanded);
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        for rows in [
+            &["...", "...", "..."][..],
+            &["....#...", ".##.#.#.", ".#..#.#.", ".#.##.#.", "......#."],
+            &["........", "........", "........", "........"],
+            &["..#.", "..#.", "..#."],
+        ] {
+            let maze = Maze::new(rows);
+            let start = Point2::new(0, 0);
+            let expected = bfs(&maze, start);
+            let found = bidirectional_bfs(&maze, start, maze.goal);
+            assert_eq!(
+                found.as_ref().map(|f| (f.cost, f.count)),
+                expected.as_ref().map(|f| (f.cost, f.count)),
+                "{rows:?}"
+            );
+            if let Some(found) = found {
+                assert_eq!(found.path.len(), found.cost + 1);
+                assert_eq!((found.path[0], *found.goal()), (start, maze.goal));
+                assert!(found.path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reachable() {
+        let maze = Maze::new(&["....", ".##.", "...."]);
+        let within = reachable(&maze, Point2::new(0, 0), 2);
+        assert_eq!(within.len(), 5);
+        assert_eq!(within[&Point2::new(0, 2)], 2);
+        assert_eq!(reachable(&maze, Point2::new(0, 0), 100).len(), 10);
+    }
+}
@@ -0,0 +1,44 @@
+use std::time::Instant;
+
+use crate::{Answer, Part, Result, Run, Timed};
+
+/// The shape every day implements. Parsing is kept separate from solving so that the parsed input
+/// can be shared between parts (and timed on its own).
+pub trait Solution {
+    /// What `parse` turns the raw input into. It may borrow from the input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// Day 25 doesn't have a second part.
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer> {
+        Err("there is no part 2".into())
+    }
+}
+
+// Parse and solve in one go. The parse is timed separately from the part.
+impl<S: Solution> Run for Part<S> {
+    fn part(&self) -> u8 {
+        self.part
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn run(&self, input: &str) -> Result<Timed> {
+        let now = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse = now.elapsed();
+
+        let now = Instant::now();
+        let answer = (self.solve)(&parsed)?;
+        Ok(Timed {
+            answer,
+            parse,
+            solve: now.elapsed(),
+        })
+    }
+}
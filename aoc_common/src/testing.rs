@@ -0,0 +1,13 @@
+/// A linear congruential generator for fixtures that are too big to write out by hand but have to
+/// come out the same every run. The low bits aren't very random, so take them from the top.
+pub(crate) struct Lcg(pub u64);
+
+impl Lcg {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+}
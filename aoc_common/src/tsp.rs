@@ -0,0 +1,242 @@
+use crate::{Coord, Grid};
+
+/// Held-Karp dynamic programming for the traveling salesman problem over a distance matrix where
+/// `distances[(from, to)]` is the cost of going from one node to another. By default it finds the
+/// shortest open path through every node starting anywhere.
+///
+/// The tables are `2^n * n` so it's good for about 20 nodes.
+///
+/// <https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm>
+#[derive(Debug, Clone, Copy)]
+pub struct HeldKarp<'a, T> {
+    distances: &'a Grid<T>,
+    cycle: bool,
+    longest: bool,
+    start: Option<usize>,
+}
+
+/// The best order to visit the nodes in and what it costs. A cycle's cost includes getting back
+/// to the start, but the start isn't repeated at the end of the order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour<T> {
+    pub order: Vec<usize>,
+    pub cost: T,
+}
+
+impl<'a, T: Coord + Ord> HeldKarp<'a, T> {
+    pub fn new(distances: &'a Grid<T>) -> Self {
+        assert_eq!(
+            distances.width(),
+            distances.height(),
+            "distances aren't square"
+        );
+        Self {
+            distances,
+            cycle: false,
+            longest: false,
+            start: None,
+        }
+    }
+
+    /// Come back to the start at the end.
+    pub fn cycle(self) -> Self {
+        Self {
+            cycle: true,
+            ..self
+        }
+    }
+
+    /// Find the most expensive tour instead of the cheapest.
+    pub fn longest(self) -> Self {
+        Self {
+            longest: true,
+            ..self
+        }
+    }
+
+    /// Always start from the given node. Cycles start from node 0 if this isn't set.
+    pub fn start(self, node: usize) -> Self {
+        Self {
+            start: Some(node),
+            ..self
+        }
+    }
+
+    /// The optimal tour.
+    pub fn solve(&self) -> Tour<T> {
+        let n = self.distances.width();
+
+        // Every tour is built out from a fixed start. An open path that can start anywhere gets a
+        // made up start that's free to leave.
+        let start = match (self.start, self.cycle) {
+            (Some(start), _) => Some(start),
+            (None, true) => Some(0),
+            (None, false) => None,
+        };
+        let others = (0..n).filter(|&i| Some(i) != start).collect::<Vec<_>>();
+        let m = others.len();
+        if m == 0 {
+            let cost = match self.cycle {
+                true => self.distances[(0, 0)],
+                false => T::ZERO,
+            };
+            return Tour {
+                order: vec![0],
+                cost,
+            };
+        }
+
+        let dist = |from: usize, to: usize| self.distances[(others[from], others[to])];
+        let leave = |to: usize| start.map_or(T::ZERO, |s| self.distances[(s, others[to])]);
+        let better = |a: T, b: T| match self.longest {
+            true => a.max(b),
+            false => a.min(b),
+        };
+
+        // best[mask * m + last] is the cost of leaving the start and visiting the others in the
+        // mask, ending at `last`. Only entries where `last` is in the mask are used.
+        let mut best = vec![T::ZERO; (1 << m) * m];
+        for mask in 1usize..1 << m {
+            for last in (0..m).filter(|&i| mask & 1 << i != 0) {
+                let prev = mask & !(1 << last);
+                best[mask * m + last] = match prev {
+                    0 => leave(last),
+                    _ => (0..m)
+                        .filter(|&i| prev & 1 << i != 0)
+                        .map(|i| best[prev * m + i] + dist(i, last))
+                        .reduce(better)
+                        .unwrap(),
+                };
+            }
+        }
+
+        let full = (1 << m) - 1;
+        let finish = |last: usize| {
+            let back = match (self.cycle, start) {
+                (true, Some(s)) => self.distances[(others[last], s)],
+                _ => T::ZERO,
+            };
+            best[full * m + last] + back
+        };
+        let cost = (0..m).map(finish).reduce(better).unwrap();
+
+        // Walk back through the table to find an order that got that cost.
+        let mut last = (0..m).find(|&i| finish(i) == cost).unwrap();
+        let mut mask = full;
+        let mut order = vec![others[last]];
+        while mask != 1 << last {
+            let prev = mask & !(1 << last);
+            let here = best[mask * m + last];
+            last = (0..m)
+                .find(|&i| prev & 1 << i != 0 && best[prev * m + i] + dist(i, last) == here)
+                .unwrap();
+            order.push(others[last]);
+            mask = prev;
+        }
+        order.extend(start);
+        order.reverse();
+
+        Tour { order, cost }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point2, testing::Lcg};
+
+    // The cost of visiting the nodes in order.
+    fn cost(distances: &Grid<i64>, order: &[usize], cycle: bool) -> i64 {
+        let mut cost = order
+            .windows(2)
+            .map(|w| distances[(w[0], w[1])])
+            .sum::<i64>();
+        if cycle {
+            cost += distances[(order[order.len() - 1], order[0])];
+        }
+        cost
+    }
+
+    fn permutations(items: Vec<usize>) -> Vec<Vec<usize>> {
+        if items.len() <= 1 {
+            return vec![items];
+        }
+        (0..items.len())
+            .flat_map(|i| {
+                let mut rest = items.clone();
+                let first = rest.remove(i);
+                permutations(rest).into_iter().map(move |mut p| {
+                    p.insert(0, first);
+                    p
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cities() {
+        // London, Dublin and Belfast.
+        let d = [[0, 464, 518], [464, 0, 141], [518, 141, 0]];
+        let distances = Grid::from_fn(3, 3, |p| d[p.y][p.x]);
+
+        let shortest = HeldKarp::new(&distances).solve();
+        assert_eq!(shortest.cost, 605);
+        assert_eq!(cost(&distances, &shortest.order, false), 605);
+        assert_eq!(HeldKarp::new(&distances).longest().solve().cost, 982);
+        assert_eq!(HeldKarp::new(&distances).cycle().solve().cost, 1123);
+    }
+
+    #[test]
+    fn test_brute_force() {
+        // Lopsided distances so the direction matters.
+        let mut rng = Lcg(12345);
+        let distances = Grid::from_fn(7, 7, |Point2 { x, y }| {
+            let r = rng.next_u64();
+            match x == y {
+                true => 0,
+                false => (r >> 33) as i64 % 100 - 20,
+            }
+        });
+
+        for (cycle, longest, start) in [
+            (false, false, None),
+            (false, true, None),
+            (false, false, Some(3)),
+            (false, true, Some(6)),
+            (true, false, None),
+            (true, true, Some(2)),
+        ] {
+            let mut solver = HeldKarp::new(&distances);
+            if cycle {
+                solver = solver.cycle();
+            }
+            if longest {
+                solver = solver.longest();
+            }
+            if let Some(start) = start {
+                solver = solver.start(start);
+            }
+            let tour = solver.solve();
+
+            let costs = permutations((0..7).collect())
+                .into_iter()
+                .filter(|p| start.is_none_or(|s| p[0] == s))
+                .map(|p| cost(&distances, &p, cycle));
+            let expected = match longest {
+                true => costs.max(),
+                false => costs.min(),
+            };
+            assert_eq!(Some(tour.cost), expected, "{cycle} {longest} {start:?}");
+            assert_eq!(cost(&distances, &tour.order, cycle), tour.cost);
+            assert_eq!(tour.order.len(), 7);
+            assert!(start.is_none_or(|s| tour.order[0] == s));
+        }
+    }
+
+    #[test]
+    fn test_one() {
+        let one = Grid::new(1, 1, 0i64);
+        let tour = HeldKarp::new(&one).cycle().solve();
+        assert_eq!((tour.order, tour.cost), (vec![0], 0));
+    }
+}